@@ -1,7 +1,16 @@
+#[cfg(feature = "si2")]
 use std::path::Path;
+#[cfg(feature = "si2")]
 use std::process::Command;
 
 fn main() {
+    // The native parser does not need the Si2 sources
+    #[cfg(feature = "si2")]
+    build_si2();
+}
+
+#[cfg(feature = "si2")]
+fn build_si2() {
     let lef_source_dir = Path::new("third_party/si2-lef/lef");
     let clef_source_dir = Path::new("third_party/si2-lef/clef");
 
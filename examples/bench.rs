@@ -16,12 +16,12 @@ fn main_result() -> Result<(), Box<dyn std::error::Error>> {
         }
         for pin in makcro.pins.iter() {
             println!("\t{}", pin.name);
-            for gs in pin.port.iter() {
+            for gs in pin.ports.iter().flat_map(|port| port.geometries.iter()) {
                 println!("\t\t{}", gs.layer_name);
                 for g in gs.geometries.iter() {
                     match g {
-                        LefGeometry::Rect(l, h) => { println!("\t\t\tRECT: {:?} {:?}", l, h) }
-                        LefGeometry::Polygon(ps) => {
+                        LefGeometry::Rect(l, h, _) => { println!("\t\t\tRECT: {:?} {:?}", l, h) }
+                        LefGeometry::Polygon(ps, _) => {
                             print!("\t\t\tPOLYGON: ");
                             for p in ps.iter() {
                                 print!("{:?} ", p);
@@ -1,3 +1,4 @@
+#[cfg(feature = "si2")]
 mod si2;
 mod model;
 mod read;
@@ -3,7 +3,7 @@ use std::fmt;
 use std::str::FromStr;
 
 /// Top-level structure of a LEF library.
-#[derive(Default, Clone, Debug)]
+#[derive(Default, Clone, Debug, PartialEq)]
 pub struct LefTechnology {
     /// LEF version.
     pub version: Option<f64>,
@@ -21,12 +21,245 @@ pub struct LefTechnology {
     pub layers: Vec<LefLayer>,
     /// Definitions of fixed VIAs by name.
     pub vias: HashMap<String, LefVia>,
+    /// Definitions of VIARULEs (generated and fixed) by name.
     pub via_rules: HashMap<String, LefViaRule>,
     /// All SITE definitions by name.
     pub sites: HashMap<String, LefSiteDefinition>,
+    /// NONDEFAULTRULE definitions by name.
+    pub non_default_rules: HashMap<String, LefNonDefaultRule>,
+    /// PROPERTYDEFINITIONS of the library.
+    pub property_definitions: Vec<LefPropertyDefinition>,
+    /// Maximum number of single-cut stacked vias (MAXVIASTACK).
+    pub max_via_stack: Option<LefMaxViaStack>,
+    /// Whether OBS shapes use the minimum spacing rules (USEMINSPACING OBS). LEF default is ON.
+    pub use_min_spacing_obs: Option<bool>,
+    /// Whether wire extension at pins is suppressed (NOWIREEXTENSIONATPIN ON). Obsolete since LEF 5.6.
+    pub no_wire_extension_at_pin: Option<bool>,
+    /// Minimum feature size in x and y (MINFEATURE).
+    pub min_feature: Option<(f64, f64)>,
+    /// Mask assignments of shapes are fixed and may not be shifted (FIXEDMASK).
+    pub fixed_mask: bool,
+    /// IRDROP tables (obsolete since LEF 5.4).
+    pub ir_drop_tables: Vec<LefIrDropTable>,
+    /// Dielectric constant for oxide (DIELECTRIC, obsolete since LEF 5.4).
+    pub dielectric: Option<f64>,
+    /// NOISEMARGIN (obsolete since LEF 5.4).
+    pub noise_margin: Option<LefNoiseMargin>,
+    /// EDGERATETHRESHOLD1 (obsolete since LEF 5.4).
+    pub edge_rate_threshold1: Option<f64>,
+    /// EDGERATETHRESHOLD2 (obsolete since LEF 5.4).
+    pub edge_rate_threshold2: Option<f64>,
+    /// EDGERATESCALEFACTOR (obsolete since LEF 5.4).
+    pub edge_rate_scale_factor: Option<f64>,
+    /// NOISETABLE, victim values are noise (obsolete since LEF 5.4).
+    pub noise_table: Option<LefCrossTalkTable>,
+    /// CORRECTIONTABLE, victim values are correction factors (obsolete since LEF 5.4).
+    pub correction_table: Option<LefCrossTalkTable>,
+    /// ARRAY definitions by name (obsolete since LEF 5.4).
+    pub arrays: HashMap<String, LefArray>,
+    /// Vendor extension blocks (BEGINEXT ... ENDEXT) in order of appearance.
+    pub extensions: Vec<LefExtension>,
+}
+
+/// MAXVIASTACK statement.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LefMaxViaStack {
+    /// Maximum allowed number of single-cut stacked vias.
+    pub max_stack: u32,
+    /// Bottom and top layer the rule applies to. Applies to all layers if absent.
+    pub range: Option<(String, String)>,
+}
+
+/// IRDROP table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefIrDropTable {
+    /// Name of the table, referenced by the TABLE statements of a macro.
+    pub name: String,
+    /// `(current, voltage)` pairs.
+    pub values: Vec<(f64, f64)>,
+}
+
+impl LefIrDropTable {
+    /// Voltage drop for `current`, interpolated linearly between table points.
+    pub fn voltage(&self, current: f64) -> Option<f64> {
+        interpolate(&self.values, current)
+    }
+}
+
+/// NOISEMARGIN statement.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LefNoiseMargin {
+    /// Maximum voltage noise on a logic high input, in volts.
+    pub high: f64,
+    /// Maximum voltage noise on a logic low input, in volts.
+    pub low: f64,
+}
+
+/// NOISETABLE or CORRECTIONTABLE.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefCrossTalkTable {
+    /// Table number.
+    pub number: i32,
+    /// Entries by EDGERATE.
+    pub edges: Vec<LefCrossTalkEdge>,
+}
+
+/// EDGERATE entry of a cross talk table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefCrossTalkEdge {
+    /// Edge rate threshold the entry applies to.
+    pub edge_rate: f64,
+    /// Entries by OUTPUTRESISTANCE.
+    pub resistances: Vec<LefCrossTalkResistance>,
+}
+
+/// OUTPUTRESISTANCE entry of a cross talk table.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefCrossTalkResistance {
+    /// Output resistances of the victim driver, in ohms.
+    pub output_resistances: Vec<f64>,
+    /// Entries by VICTIMLENGTH.
+    pub victims: Vec<LefCrossTalkVictim>,
 }
 
-#[derive(Default, Clone, Debug)]
+/// VICTIMLENGTH entry of a cross talk table, with one value per output resistance.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefCrossTalkVictim {
+    /// Length of the victim net, in microns.
+    pub length: f64,
+    /// Noise or correction factors, in the order of `output_resistances`.
+    pub values: Vec<f64>,
+}
+
+impl LefCrossTalkTable {
+    /// Table value for an edge rate, output resistance and victim length, interpolated linearly in each dimension.
+    pub fn lookup(&self, edge_rate: f64, output_resistance: f64, victim_length: f64) -> Option<f64> {
+        let points: Vec<(f64, f64)> = self.edges
+            .iter()
+            .filter_map(|edge| Some((edge.edge_rate, edge.lookup(output_resistance, victim_length)?)))
+            .collect();
+        interpolate(&points, edge_rate)
+    }
+}
+
+impl LefCrossTalkEdge {
+    /// Table value for an output resistance and victim length. Uses the OUTPUTRESISTANCE entry closest to the resistance.
+    pub fn lookup(&self, output_resistance: f64, victim_length: f64) -> Option<f64> {
+        self.resistances
+            .iter()
+            .filter(|resistance| !resistance.output_resistances.is_empty())
+            .min_by(|a, b| a.distance(output_resistance).total_cmp(&b.distance(output_resistance)))?
+            .lookup(output_resistance, victim_length)
+    }
+}
+
+impl LefCrossTalkResistance {
+    /// Table value for an output resistance and victim length.
+    pub fn lookup(&self, output_resistance: f64, victim_length: f64) -> Option<f64> {
+        let points: Vec<(f64, f64)> = self.victims
+            .iter()
+            .filter_map(|victim| {
+                let values: Vec<(f64, f64)> = self.output_resistances
+                    .iter()
+                    .copied()
+                    .zip(victim.values.iter().copied())
+                    .collect();
+                Some((victim.length, interpolate(&values, output_resistance)?))
+            })
+            .collect();
+        interpolate(&points, victim_length)
+    }
+
+    fn distance(&self, output_resistance: f64) -> f64 {
+        let min = self.output_resistances.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = self.output_resistances.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        (min - output_resistance).max(output_resistance - max).max(0.0)
+    }
+}
+
+/// Piecewise linear interpolation of `(x, y)` points, clamped to the first and last point.
+fn interpolate(points: &[(f64, f64)], x: f64) -> Option<f64> {
+    let mut points = points.to_vec();
+    points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let (first, last) = (points.first()?, points.last()?);
+    if x <= first.0 {
+        return Some(first.1);
+    }
+    if x >= last.0 {
+        return Some(last.1);
+    }
+
+    points
+        .windows(2)
+        .find(|window| x <= window[1].0)
+        .map(|window| {
+            let ((x0, y0), (x1, y1)) = (window[0], window[1]);
+            y0 + (y1 - y0) * (x - x0) / (x1 - x0)
+        })
+}
+
+/// ARRAY definition of a gate array or floorplan (obsolete since LEF 5.4).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefArray {
+    /// Name of the array.
+    pub name: String,
+    /// Site placements of the array (SITE).
+    pub sites: Vec<LefSite>,
+    /// Sites where macros can be placed (CANPLACE).
+    pub can_place: Vec<LefSite>,
+    /// Sites which can not be occupied (CANNOTOCCUPY).
+    pub cannot_occupy: Vec<LefSite>,
+    /// Routing tracks (TRACKS).
+    pub tracks: Vec<LefTrackPattern>,
+    /// Global routing grid (GCELLGRID).
+    pub gcell_grids: Vec<LefGcellPattern>,
+    /// Default wire capacitance by minimum number of pins (DEFAULTCAP).
+    pub default_caps: Vec<(u32, f64)>,
+    /// Named FLOORPLANs of the array.
+    pub floor_plans: Vec<LefFloorPlan>,
+}
+
+/// TRACKS of an ARRAY.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefTrackPattern {
+    /// `X` for vertical tracks at x coordinates, `Y` for horizontal tracks at y coordinates.
+    pub name: String,
+    /// Coordinate of the first track.
+    pub start: f64,
+    /// Number of tracks.
+    pub num_tracks: u32,
+    /// Distance between the tracks.
+    pub space: f64,
+    /// Routing layers of the tracks.
+    pub layers: Vec<String>,
+}
+
+/// GCELLGRID of an ARRAY.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefGcellPattern {
+    /// `X` for columns, `Y` for rows.
+    pub name: String,
+    /// Coordinate of the first grid line.
+    pub start: f64,
+    /// Number of columns or rows.
+    pub num_crs: u32,
+    /// Distance between the grid lines.
+    pub space: f64,
+}
+
+/// FLOORPLAN of an ARRAY.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefFloorPlan {
+    /// Name of the floorplan.
+    pub name: String,
+    /// Sites where macros can be placed (CANPLACE).
+    pub can_place: Vec<LefSite>,
+    /// Sites which can not be occupied (CANNOTOCCUPY).
+    pub cannot_occupy: Vec<LefSite>,
+}
+
+#[derive(Default, Clone, Debug, PartialEq)]
 pub struct LefCellLibrary {
     /// LEF version.
     pub version: Option<f64>,
@@ -40,6 +273,37 @@ pub struct LefCellLibrary {
     pub sites: HashMap<String, LefSiteDefinition>,
     /// macro definitions of the library.
     pub macros: HashMap<String, LefMacro>,
+    /// PROPERTYDEFINITIONS of the library.
+    pub property_definitions: Vec<LefPropertyDefinition>,
+    /// Vendor extension blocks (BEGINEXT ... ENDEXT) in order of appearance.
+    pub extensions: Vec<LefExtension>,
+}
+
+/// Vendor extension block `BEGINEXT "tag" ... ENDEXT`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LefExtension {
+    /// Tag of the extension without quotes.
+    pub tag: String,
+    /// Unparsed text between the tag and ENDEXT, including whitespace and line breaks.
+    pub text: String,
+}
+
+impl FromStr for LefExtension {
+    type Err = ();
+
+    /// Parse the text following BEGINEXT up to and including ENDEXT.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = input.trim_start().strip_prefix('"').ok_or(())?;
+        let (tag, text) = input.split_once('"').ok_or(())?;
+        let text = text.strip_suffix("ENDEXT").unwrap_or(text);
+        Ok(Self { tag: tag.to_string(), text: text.to_string() })
+    }
+}
+
+impl fmt::Display for LefExtension {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BEGINEXT \"{}\"{}ENDEXT", self.tag, self.text)
+    }
 }
 
 impl LefTechnology {
@@ -51,27 +315,111 @@ impl LefTechnology {
             ..Default::default()
         }
     }
+
+    /// Find the definition of a property by object type and name.
+    pub fn property_definition(&self, object_type: LefPropertyObjectType, name: &str) -> Option<&LefPropertyDefinition> {
+        find_property_definition(&self.property_definitions, object_type, name)
+    }
+}
+
+impl LefCellLibrary {
+    /// Find the definition of a property by object type and name.
+    pub fn property_definition(&self, object_type: LefPropertyObjectType, name: &str) -> Option<&LefPropertyDefinition> {
+        find_property_definition(&self.property_definitions, object_type, name)
+    }
+}
+
+pub(crate) fn find_property_definition<'a>(definitions: &'a [LefPropertyDefinition], object_type: LefPropertyObjectType, name: &str) -> Option<&'a LefPropertyDefinition> {
+    definitions
+        .iter()
+        .find(|definition| definition.object_type == object_type && definition.name == name)
 }
 
 /// Units used in the library.
-#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+///
+/// Values in a LEF file are always in microns, nano seconds, pico farads, ohms, milli watts,
+/// milli amperes, volts and mega hertz. Each factor of the UNITS statement is the number of DEF
+/// database units per such LEF unit.
+#[derive(Copy, Clone, Debug, PartialEq)]
 pub struct LefUnits {
-    /// Time in nano seconds.
-    pub time_ns: u64,
-    /// Capacitance in pico farads.
-    pub capacitance_pf: u64,
-    /// Resistance in ohms.
-    pub resistance_ohms: u64,
-    /// Power in milli watts.
-    pub power_mw: u64,
-    /// Current in milli amperes.
-    pub current_ma: u64,
-    /// Voltage in volts.
-    pub voltage_v: u64,
-    /// Length in micro meters.
-    pub database_microns: u64,
-    /// Frequency in mega hertz.
-    pub frequency_mega_hz: u64,
+    /// Database units per nano second (TIME NANOSECONDS). Default is 1.
+    pub time_ns: f64,
+    /// Database units per pico farad (CAPACITANCE PICOFARADS). Default is 1.
+    pub capacitance_pf: f64,
+    /// Database units per ohm (RESISTANCE OHMS). Default is 1.
+    pub resistance_ohms: f64,
+    /// Database units per milli watt (POWER MILLIWATTS). Default is 1.
+    pub power_mw: f64,
+    /// Database units per milli ampere (CURRENT MILLIAMPS). Default is 1.
+    pub current_ma: f64,
+    /// Database units per volt (VOLTAGE VOLTS). Default is 1.
+    pub voltage_v: f64,
+    /// Database units per micron (DATABASE MICRONS). Default is 100.
+    pub database_microns: f64,
+    /// Database units per mega hertz (FREQUENCY MEGAHERTZ). Default is 1.
+    pub frequency_mega_hz: f64,
+}
+
+impl Default for LefUnits {
+    fn default() -> Self {
+        Self {
+            time_ns: 1.0,
+            capacitance_pf: 1.0,
+            resistance_ohms: 1.0,
+            power_mw: 1.0,
+            current_ma: 1.0,
+            voltage_v: 1.0,
+            database_microns: 100.0,
+            frequency_mega_hz: 1.0,
+        }
+    }
+}
+
+impl LefUnits {
+    /// Convert a length in microns into database units, rounded to the nearest unit.
+    pub fn to_dbu(&self, microns: f64) -> i64 {
+        (microns * self.database_microns).round() as i64
+    }
+
+    /// Convert database units into a length in microns.
+    pub fn from_dbu(&self, dbu: i64) -> f64 {
+        dbu as f64 / self.database_microns
+    }
+
+    /// Convert a time in nano seconds into the database scale of TIME NANOSECONDS.
+    pub fn time_to_db(&self, ns: f64) -> f64 {
+        ns * self.time_ns
+    }
+
+    /// Convert a capacitance in pico farads into the database scale of CAPACITANCE PICOFARADS.
+    pub fn capacitance_to_db(&self, pf: f64) -> f64 {
+        pf * self.capacitance_pf
+    }
+
+    /// Convert a resistance in ohms into the database scale of RESISTANCE OHMS.
+    pub fn resistance_to_db(&self, ohms: f64) -> f64 {
+        ohms * self.resistance_ohms
+    }
+
+    /// Convert a power in milli watts into the database scale of POWER MILLIWATTS.
+    pub fn power_to_db(&self, mw: f64) -> f64 {
+        mw * self.power_mw
+    }
+
+    /// Convert a current in milli amperes into the database scale of CURRENT MILLIAMPS.
+    pub fn current_to_db(&self, ma: f64) -> f64 {
+        ma * self.current_ma
+    }
+
+    /// Convert a voltage in volts into the database scale of VOLTAGE VOLTS.
+    pub fn voltage_to_db(&self, volts: f64) -> f64 {
+        volts * self.voltage_v
+    }
+
+    /// Convert a frequency in mega hertz into the database scale of FREQUENCY MEGAHERTZ.
+    pub fn frequency_to_db(&self, mega_hz: f64) -> f64 {
+        mega_hz * self.frequency_mega_hz
+    }
 }
 
 /// Macro SITE declaration.
@@ -110,15 +458,30 @@ pub struct LefSiteDefinition {
 /// Use `each_offset()` to iterate through all offsets described by this pattern.
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub struct LefStepPattern {
+    /// Spacing between columns.
     pub x_step: f64,
+    /// Spacing between rows.
     pub y_step: f64,
+    /// Number of columns (DO).
     pub x_start: f64,
+    /// Number of rows (BY).
     pub y_start: f64,
 }
 
+impl LefStepPattern {
+    /// Iterate through the offsets of all repetitions relative to the first element, row by row.
+    pub fn each_offset(&self) -> impl Iterator<Item = (f64, f64)> {
+        let pattern = *self;
+        let (num_x, num_y) = (pattern.x_start.round() as usize, pattern.y_start.round() as usize);
+        (0..num_y).flat_map(move |row| {
+            (0..num_x).map(move |col| (col as f64 * pattern.x_step, row as f64 * pattern.y_step))
+        })
+    }
+}
+
 /// Holds either the value of the SPACING argument or DESIGNRULEWIDTH argument of a geometrical
 /// layer as used in the LAYER definition in PIN or OBS.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum LefSpacingOrDesignRuleWidth {
     /// Minimal allowed spacing between this shape and other shapes.
     MinSpacing(f64),
@@ -126,25 +489,96 @@ pub enum LefSpacingOrDesignRuleWidth {
     DesignRuleWidth(f64),
 }
 
-/// Either a path, rectangle or polygon.
-#[derive(Clone, Debug)]
+/// Either a path, rectangle or polygon. Shapes carry their MASK number, if any.
+#[derive(Clone, Debug, PartialEq)]
 pub enum LefGeometry {
-    /// Width and path.
-    Path(f64, Vec<(f64, f64)>),
+    /// Width and path. Without width the default width of the layer is used.
+    Path(Option<f64>, Vec<(f64, f64)>, Option<u32>),
     /// Corner points of a rectangle.
-    Rect((f64, f64), (f64, f64)),
+    Rect((f64, f64), (f64, f64), Option<u32>),
     /// Vertices of a polygon.
-    Polygon(Vec<(f64, f64)>),
+    Polygon(Vec<(f64, f64)>, Option<u32>),
+    /// Array of a geometry (ITERATE). Use `expand()` to get the individual shapes.
+    Iterate(Box<LefGeometry>, LefStepPattern),
 }
 
-#[derive(Clone, Debug)]
+impl LefGeometry {
+    /// Get the MASK number of the shape, if any.
+    pub fn mask(&self) -> Option<u32> {
+        match self {
+            Self::Path(_, _, mask) => *mask,
+            Self::Rect(_, _, mask) => *mask,
+            Self::Polygon(_, mask) => *mask,
+            Self::Iterate(geometry, _) => geometry.mask(),
+        }
+    }
+
+    /// Get the geometry moved by an offset.
+    pub fn translated(&self, (dx, dy): (f64, f64)) -> Self {
+        let translate = |points: &Vec<(f64, f64)>| points.iter().map(|(x, y)| (x + dx, y + dy)).collect();
+        match self {
+            Self::Path(width, points, mask) => Self::Path(*width, translate(points), *mask),
+            Self::Rect((xl, yl), (xh, yh), mask) => Self::Rect((xl + dx, yl + dy), (xh + dx, yh + dy), *mask),
+            Self::Polygon(points, mask) => Self::Polygon(translate(points), *mask),
+            Self::Iterate(geometry, pattern) => Self::Iterate(Box::new(geometry.translated((dx, dy))), *pattern),
+        }
+    }
+
+    /// Expand ITERATE arrays into the individual shapes.
+    pub fn expand(&self) -> Vec<LefGeometry> {
+        match self {
+            Self::Iterate(geometry, pattern) => pattern
+                .each_offset()
+                .flat_map(|offset| geometry.translated(offset).expand())
+                .collect(),
+            _ => vec![self.clone()],
+        }
+    }
+}
+
+/// VIARULE definition.
+#[derive(Clone, Debug, PartialEq)]
 pub enum LefViaRule {
+    /// VIARULE GENERATE, formula to generate vias between two routing layers.
     Generate(LefViaGenerateRule),
-    // TODO: Fixed
+    /// VIARULE without GENERATE, list of predefined vias to use between two routing layers.
+    Fixed(LefViaFixedRule),
+}
+
+impl LefViaRule {
+    /// Get the name of the via rule.
+    pub fn name(&self) -> &String {
+        match self {
+            LefViaRule::Generate(r) => &r.rule_name,
+            LefViaRule::Fixed(r) => &r.rule_name,
+        }
+    }
+}
+
+/// A via rule which selects from a list of predefined vias.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefViaFixedRule {
+    /// Name of the via rule.
+    pub rule_name: String,
+    /// The two routing layers connected by the vias.
+    pub layers: (LefViaRuleLayer, LefViaRuleLayer),
+    /// Names of the vias that can be used by this rule.
+    pub vias: Vec<String>,
+}
+
+/// LAYER of a fixed VIARULE.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefViaRuleLayer {
+    /// Name of the routing layer.
+    pub name: String,
+    /// Wire direction the rule applies to.
+    pub direction: Option<LefRoutingDirection>,
+    /// (minwidth, maxwidth) of the wires the rule applies to.
+    pub width: Option<(f64, f64)>,
 }
 
 /// A generated via.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct LefViaGenerateRule {
     /// Default via to be used for routing between the adjacent layers.
     pub is_default: bool,
@@ -162,28 +596,268 @@ pub struct LefViaGenerateRule {
     pub spacing: (f64, f64),
 }
 
-/// Either a rectangle or a polygon.
-#[derive(Clone, Debug)]
+/// Either a rectangle or a polygon, with an optional MASK number.
+#[derive(Clone, Debug, PartialEq)]
 pub enum LefViaShape {
     /// Axis-aligned rectangle.
-    Rect((f64, f64), (f64, f64)),
+    Rect((f64, f64), (f64, f64), Option<u32>),
     /// Polygon.
-    Polygon(Vec<(f64, f64)>),
+    Polygon(Vec<(f64, f64)>, Option<u32>),
+}
+
+impl LefViaShape {
+    /// Get the MASK number of the shape, if any.
+    pub fn mask(&self) -> Option<u32> {
+        match self {
+            LefViaShape::Rect(_, _, mask) => *mask,
+            LefViaShape::Polygon(_, mask) => *mask,
+        }
+    }
+
+    /// Get the shape with another MASK number.
+    pub fn with_mask(self, mask: Option<u32>) -> Self {
+        match self {
+            LefViaShape::Rect(p1, p2, _) => LefViaShape::Rect(p1, p2, mask),
+            LefViaShape::Polygon(points, _) => LefViaShape::Polygon(points, mask),
+        }
+    }
+
+    /// Get the shape moved by an offset.
+    pub fn translated(&self, (dx, dy): (f64, f64)) -> Self {
+        match self {
+            LefViaShape::Rect((xl, yl), (xh, yh), mask) => LefViaShape::Rect((xl + dx, yl + dy), (xh + dx, yh + dy), *mask),
+            LefViaShape::Polygon(points, mask) => LefViaShape::Polygon(points.iter().map(|(x, y)| (x + dx, y + dy)).collect(), *mask),
+        }
+    }
+}
+
+/// Shapes of a via on one layer.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefViaLayer {
+    /// Name of the layer.
+    pub name: String,
+    /// Shapes on this layer.
+    pub shapes: Vec<LefViaShape>,
 }
 
 /// An explicitly defined via.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct LefVia {
     /// Default via to be used for routing between the adjacent layers.
     pub is_default: bool,
     /// Electrical resistance of the via.
     pub resistance: Option<f64>,
-    /// Layers and shapes of the via geometry.
-    pub geometry: HashMap<String, Vec<LefViaShape>>,
+    /// Layers and shapes of the via geometry, in the order of the file.
+    pub geometry: Vec<LefViaLayer>,
+    /// Parameters of a via generated from a VIARULE. The `geometry` is empty in this case.
+    pub via_rule: Option<LefViaRuleParams>,
+    /// Custom properties.
+    pub properties: HashMap<String, LefPropertyValue>,
+}
+
+impl LefVia {
+    /// Get the concrete shapes on each layer, expanding the VIARULE parameters if present.
+    /// Returns `None` if the cut PATTERN of the parameters is malformed.
+    pub fn shapes(&self) -> Option<Vec<LefViaLayer>> {
+        match &self.via_rule {
+            Some(params) => params.shapes(),
+            None => Some(self.geometry.clone()),
+        }
+    }
+
+    /// Get the routing layer below the cut layer.
+    pub fn bottom_layer<'a>(&self, tech: &'a LefTechnology) -> Option<&'a LefLayer> {
+        self.resolve_layers(tech)
+            .into_iter()
+            .find(|layer| !matches!(layer, LefLayer::Cut(_)))
+    }
+
+    /// Get the cut layer.
+    pub fn cut_layer<'a>(&self, tech: &'a LefTechnology) -> Option<&'a LefLayer> {
+        self.resolve_layers(tech)
+            .into_iter()
+            .find(|layer| matches!(layer, LefLayer::Cut(_)))
+    }
+
+    /// Get the routing layer above the cut layer.
+    pub fn top_layer<'a>(&self, tech: &'a LefTechnology) -> Option<&'a LefLayer> {
+        self.resolve_layers(tech)
+            .into_iter()
+            .rev()
+            .find(|layer| !matches!(layer, LefLayer::Cut(_)))
+    }
+
+    /// Look up the layers of the via in the technology, ordered from bottom to top.
+    fn resolve_layers<'a>(&self, tech: &'a LefTechnology) -> Vec<&'a LefLayer> {
+        let names: Vec<&String> = match &self.via_rule {
+            Some(params) => vec![&params.layers.0, &params.layers.1, &params.layers.2],
+            None => self.geometry.iter().map(|layer| &layer.name).collect(),
+        };
+        tech.layers
+            .iter()
+            .filter(|layer| names.contains(&layer.name()))
+            .collect()
+    }
+}
+
+/// Parameters of a via generated from a VIARULE (VIARULE, CUTSIZE, LAYERS, ...).
+#[derive(Clone, Debug, PartialEq)]
+pub struct LefViaRuleParams {
+    /// Name of the VIARULE GENERATE rule used to create the via.
+    pub rule_name: String,
+    /// Width and height of the cuts.
+    pub cut_size: (f64, f64),
+    /// Bottom metal, cut and top metal layer.
+    pub layers: (String, String, String),
+    /// Edge-to-edge spacing between the cuts in x and y.
+    pub cut_spacing: (f64, f64),
+    /// (bottom-x, bottom-y), (top-x, top-y) enclosure of the cut array.
+    pub enclosure: ((f64, f64), (f64, f64)),
+    /// Number of cut rows and columns. Default is a single cut.
+    pub row_col: (u32, u32),
+    /// Offset added to all shapes of the via.
+    pub origin: (f64, f64),
+    /// (bottom-x, bottom-y), (top-x, top-y) offset added to the metal shapes.
+    pub offset: ((f64, f64), (f64, f64)),
+    /// Encoded cut PATTERN. All cuts are present if not specified.
+    pub pattern: Option<String>,
+}
+
+impl Default for LefViaRuleParams {
+    fn default() -> Self {
+        Self {
+            rule_name: Default::default(),
+            cut_size: (0.0, 0.0),
+            layers: Default::default(),
+            cut_spacing: (0.0, 0.0),
+            enclosure: ((0.0, 0.0), (0.0, 0.0)),
+            row_col: (1, 1),
+            origin: (0.0, 0.0),
+            offset: ((0.0, 0.0), (0.0, 0.0)),
+            pattern: None,
+        }
+    }
+}
+
+impl LefViaRuleParams {
+    /// Expand the parameters into the shapes of the bottom metal, cut and top metal layer.
+    ///
+    /// The cut array is centered at `(0, 0)`, the metal rectangles enclose it
+    /// and are shifted by OFFSET, then all shapes are shifted by ORIGIN.
+    /// Returns `None` if the PATTERN is malformed or does not have one row per cut row.
+    pub fn shapes(&self) -> Option<Vec<LefViaLayer>> {
+        let (rows, cols) = self.row_col;
+        let (cut_w, cut_h) = self.cut_size;
+        let (space_x, space_y) = self.cut_spacing;
+        let (origin_x, origin_y) = self.origin;
+
+        let array_w = cols as f64 * cut_w + cols.saturating_sub(1) as f64 * space_x;
+        let array_h = rows as f64 * cut_h + rows.saturating_sub(1) as f64 * space_y;
+
+        let metal = |(enc_x, enc_y): (f64, f64), (off_x, off_y): (f64, f64)| {
+            LefViaShape::Rect(
+                (-array_w / 2.0 - enc_x + off_x + origin_x, -array_h / 2.0 - enc_y + off_y + origin_y),
+                (array_w / 2.0 + enc_x + off_x + origin_x, array_h / 2.0 + enc_y + off_y + origin_y),
+                None,
+            )
+        };
+
+        let pattern = match self.pattern.as_deref() {
+            Some(pattern) => Some(decode_cut_pattern(pattern, rows as usize, cols as usize)?),
+            None => None,
+        };
+        let mut cuts = vec![];
+        for row in 0..rows as usize {
+            for col in 0..cols as usize {
+                if pattern.as_ref().is_some_and(|pattern| !pattern[row][col]) {
+                    continue;
+                }
+                let xl = -array_w / 2.0 + col as f64 * (cut_w + space_x) + origin_x;
+                let yl = -array_h / 2.0 + row as f64 * (cut_h + space_y) + origin_y;
+                cuts.push(LefViaShape::Rect((xl, yl), (xl + cut_w, yl + cut_h), None));
+            }
+        }
+
+        Some(vec![
+            LefViaLayer { name: self.layers.0.clone(), shapes: vec![metal(self.enclosure.0, self.offset.0)] },
+            LefViaLayer { name: self.layers.1.clone(), shapes: cuts },
+            LefViaLayer { name: self.layers.2.clone(), shapes: vec![metal(self.enclosure.1, self.offset.1)] },
+        ])
+    }
+}
+
+/// Decode a cut PATTERN (`numRows_rowDefinition[_numRows_rowDefinition]...`) into
+/// `rows x cols` flags, rows from bottom to top. Returns `None` for a malformed pattern.
+fn decode_cut_pattern(pattern: &str, rows: usize, cols: usize) -> Option<Vec<Vec<bool>>> {
+    let mut result = vec![];
+    let mut items = pattern.split('_');
+    while let Some(num_rows) = items.next() {
+        let num_rows = usize::from_str_radix(num_rows, 16).ok()?;
+        let mut row = vec![];
+        let mut chars = items.next()?.chars();
+        while let Some(c) = chars.next() {
+            let (repeat, digit) = match c {
+                'R' | 'r' => (chars.next()?.to_digit(16)?, chars.next()?.to_digit(16)?),
+                _ => (1, c.to_digit(16)?),
+            };
+            for _ in 0..repeat {
+                row.extend((0..4).rev().map(|bit| digit & (1 << bit) != 0));
+            }
+        }
+        // Bits beyond the number of columns are ignored.
+        row.resize(cols, false);
+        for _ in 0..num_rows {
+            result.push(row.clone());
+        }
+    }
+
+    if result.len() == rows { Some(result) } else { None }
+}
+
+/// NONDEFAULTRULE definition, wiring rules other than the default ones (e.g. wide clock wires).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefNonDefaultRule {
+    /// Name of the rule.
+    pub name: String,
+    /// HARDSPACING, the spacing values must be honored as hard rules.
+    pub hard_spacing: bool,
+    /// Wiring rules for each routing layer.
+    pub layers: Vec<LefNonDefaultRuleLayer>,
+    /// Vias defined inside the rule, by name.
+    pub vias: HashMap<String, LefVia>,
+    /// USEVIA, names of previously defined vias used by this rule.
+    pub use_vias: Vec<String>,
+    /// USEVIARULE, names of previously defined VIARULE GENERATE rules used by this rule.
+    pub use_via_rules: Vec<String>,
+    /// MINCUTS, `(cut layer, number of cuts)` tuples.
+    pub min_cuts: Vec<(String, u32)>,
+    /// Custom properties.
+    pub properties: HashMap<String, LefPropertyValue>,
+}
+
+/// LAYER of a NONDEFAULTRULE.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefNonDefaultRuleLayer {
+    /// Name of the routing layer.
+    pub name: String,
+    /// Wire width in microns.
+    pub width: Option<f64>,
+    /// Width of diagonal wires in microns.
+    pub diag_width: Option<f64>,
+    /// Minimum spacing in microns.
+    pub spacing: Option<f64>,
+    /// Length of extension of a wire over a via.
+    pub wire_extension: Option<f64>,
+    /// Sheet resistance `[Ohm/square]`.
+    pub resistance: Option<f64>,
+    /// Wire-to-ground capacitance per square unit `[pF/um^2]`.
+    pub capacitance: Option<f64>,
+    /// `[pF/um]`.
+    pub edge_capacitance: Option<f64>,
 }
 
 /// MACRO definition.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct LefMacro {
     /// Name of the macro.
     pub name: String,
@@ -193,14 +867,27 @@ pub struct LefMacro {
     /// When set, shifting of macro pin mask assignments to other masks is not allowed.
     /// Used for technologies that use multi-mask patterning.
     pub fixed_mask: bool,
-    /// Name of the corresponding cell layout in the GDS/OASIS file.
-    /// Associated with an offset and orientation.
-    pub foreign: Vec<(String, (f64, f64), LefOrient)>,
+    /// Corresponding cell layouts in the GDS/OASIS file (FOREIGN).
+    pub foreign: Vec<LefForeign>,
     /// Coordinate of the origin of the macro. Default is (0, 0).
     /// A placement of a cell in DEF is given by the location of the origin.
     pub origin: (f64, f64),
     /// Name of electrically equivalent macro.
     pub eeq: Option<String>,
+    /// Name of a macro with the same layout (LEQ, obsolete).
+    pub leq: Option<String>,
+    /// Origin of the macro definition (SOURCE, obsolete since LEF 5.6).
+    pub source: Option<LefMacroSource>,
+    /// Power consumption (POWER, obsolete since LEF 5.4).
+    pub power: Option<f64>,
+    /// Name of the generator that created the macro (GENERATOR, obsolete).
+    pub generator: Option<String>,
+    /// Generator and parameters used to create the macro (GENERATE, obsolete).
+    pub generate: Option<(String, String)>,
+    /// FUNCTION of the macro (obsolete).
+    pub function: Option<LefMacroFunction>,
+    /// Name of the clock pin (CLOCKTYPE, obsolete).
+    pub clock_type: Option<String>,
     /// Width and height of the macro.
     pub size: Option<(f64, f64)>,
     /// Symmetry of the macro. Tells how the macro can be mirrored and rotated.
@@ -213,15 +900,299 @@ pub struct LefMacro {
     pub pins: Vec<LefMacroPin>,
     /// Obstructions (blockages).
     pub obs: Vec<LefLayerGeometries>,
-    /// Density specifications.
-    pub density: Vec<()>,
+    /// Vias placed in the obstructions.
+    pub obs_vias: Vec<LefViaInstance>,
+    /// Density specifications by layer (DENSITY).
+    pub density: Vec<LefDensityLayer>,
+    /// Pin-to-pin timing (TIMING, obsolete since LEF 5.4).
+    pub timings: Vec<LefTiming>,
 
     /// Additional properties of the macro.
-    pub properties: HashMap<String, ()>,
+    pub properties: HashMap<String, LefPropertyValue>,
+}
+
+impl LefMacro {
+    /// Find a pin by name.
+    pub fn pin(&self, name: &str) -> Option<&LefMacroPin> {
+        self.pins.iter().find(|pin| pin.name == name)
+    }
+
+    /// Get the power pin a pin belongs to. This is the SUPPLYSENSITIVITY pin or, without it,
+    /// the only POWER pin of the macro.
+    pub fn supply_pin(&self, pin: &LefMacroPin) -> Option<&LefMacroPin> {
+        self.sensitivity_pin(pin.supply_sensitivity.as_deref(), LefSignalUse::Power)
+    }
+
+    /// Get the ground pin a pin belongs to. This is the GROUNDSENSITIVITY pin or, without it,
+    /// the only GROUND pin of the macro.
+    pub fn ground_pin(&self, pin: &LefMacroPin) -> Option<&LefMacroPin> {
+        self.sensitivity_pin(pin.ground_sensitivity.as_deref(), LefSignalUse::Ground)
+    }
+
+    fn sensitivity_pin(&self, name: Option<&str>, signal_use: LefSignalUse) -> Option<&LefMacroPin> {
+        if let Some(name) = name {
+            return self.pin(name);
+        }
+        let mut pins = self.pins.iter().filter(|pin| pin.signal_use.as_ref() == Some(&signal_use));
+        match (pins.next(), pins.next()) {
+            (Some(pin), None) => Some(pin),
+            _ => None,
+        }
+    }
+}
+
+/// FOREIGN reference of a macro to a cell in the GDS/OASIS file.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefForeign {
+    /// Name of the foreign cell.
+    pub cell_name: String,
+    /// Offset of the foreign cell relative to the macro origin.
+    pub point: Option<(f64, f64)>,
+    /// Orientation of the foreign cell.
+    pub orient: Option<LefOrient>,
+}
+
+impl LefForeign {
+    /// Get the offset of the foreign cell. Default is (0, 0).
+    pub fn offset(&self) -> (f64, f64) {
+        self.point.unwrap_or((0.0, 0.0))
+    }
+
+    /// Get the orientation of the foreign cell. Default is N.
+    pub fn orientation(&self) -> LefOrient {
+        self.orient.unwrap_or_default()
+    }
+}
+
+/// SOURCE of a macro.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LefMacroSource {
+    /// Macro defined by the user.
+    USER,
+    /// Macro created by a generator.
+    GENERATE,
+    /// Macro created from a block.
+    BLOCK,
+}
+
+impl FromStr for LefMacroSource {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "USER" => Ok(Self::USER),
+            "GENERATE" => Ok(Self::GENERATE),
+            "BLOCK" => Ok(Self::BLOCK),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for LefMacroSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::USER => f.write_str("USER"),
+            Self::GENERATE => f.write_str("GENERATE"),
+            Self::BLOCK => f.write_str("BLOCK"),
+        }
+    }
+}
+
+/// FUNCTION of a macro.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LefMacroFunction {
+    /// Buffer.
+    BUFFER,
+    /// Inverter.
+    INVERTER,
+}
+
+impl fmt::Display for LefMacroFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BUFFER => f.write_str("BUFFER"),
+            Self::INVERTER => f.write_str("INVERTER"),
+        }
+    }
+}
+
+/// TIMING arc of a macro between a set of input and output pins (obsolete since LEF 5.4).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefTiming {
+    /// Input pins of the arc (FROMPIN).
+    pub from_pins: Vec<String>,
+    /// Output pins of the arc (TOPIN).
+    pub to_pins: Vec<String>,
+    /// Intrinsic delay of a rising output (RISE INTRINSIC).
+    pub rise_intrinsic: Option<LefTimingIntrinsic>,
+    /// Intrinsic delay of a falling output (FALL INTRINSIC).
+    pub fall_intrinsic: Option<LefTimingIntrinsic>,
+    /// Rise delay per unit of resistance (RISERS).
+    pub rise_rs: Option<(f64, f64)>,
+    /// Fall delay per unit of resistance (FALLRS).
+    pub fall_rs: Option<(f64, f64)>,
+    /// Rise delay per unit of capacitance (RISECS).
+    pub rise_cs: Option<(f64, f64)>,
+    /// Fall delay per unit of capacitance (FALLCS).
+    pub fall_cs: Option<(f64, f64)>,
+    /// Rise saturation attenuation (RISESATT1).
+    pub rise_satt1: Option<(f64, f64)>,
+    /// Fall saturation attenuation (FALLSATT1).
+    pub fall_satt1: Option<(f64, f64)>,
+    /// Rise saturation time (RISET0).
+    pub rise_t0: Option<(f64, f64)>,
+    /// Fall saturation time (FALLT0).
+    pub fall_t0: Option<(f64, f64)>,
+    /// Unateness of the arc (UNATENESS).
+    pub unateness: Option<LefTimingUnateness>,
+    /// Setup and hold constraint (STABLE).
+    pub stable: Option<LefTimingStable>,
+    /// Dimension of the delay table (DELAY ... TABLEDIMENSION).
+    pub delay: Option<LefTimingTable>,
+    /// Dimension of the transition time table (TRANSITIONTIME ... TABLEDIMENSION).
+    pub transition: Option<LefTimingTable>,
+    /// Axis values of the table (TABLEAXIS).
+    pub table_axis: Vec<f64>,
+    /// Entries of the table (TABLEENTRIES).
+    pub table_entries: Vec<(f64, f64, f64)>,
+    /// SDF timing check (SETUP, HOLD, RECOVERY, SKEW, MPWH, MPWL or PERIOD).
+    pub sdf_trigger: Option<LefSdfTrigger>,
+    /// SDFCONDSTART expression.
+    pub sdf_cond_start: Option<String>,
+    /// SDFCONDEND expression.
+    pub sdf_cond_end: Option<String>,
+    /// SDFCOND expression.
+    pub sdf_cond: Option<String>,
+}
+
+/// INTRINSIC delay of a TIMING arc.
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct LefTimingIntrinsic {
+    /// Minimum and maximum intrinsic delay.
+    pub intrinsic: (f64, f64),
+    /// Slew parameters `(slewT1Min, slewT1Max, slewT2Min, slewT2Max)`.
+    pub slew: Option<(f64, f64, f64, f64)>,
+    /// Slew parameters `(slewT1, slewT2, slewT3)` of the second slew segment.
+    pub slew2: Option<(f64, f64, f64)>,
+    /// Minimum and maximum delay per unit of load capacitance (VARIABLE).
+    pub variable: (f64, f64),
+}
+
+/// Table dimension of a DELAY or TRANSITIONTIME table.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LefTimingTable {
+    /// Output transition of the table.
+    pub rise_fall: LefRiseFall,
+    /// Unateness of the arc.
+    pub unateness: LefTimingUnateness,
+    /// Size of the table.
+    pub dimension: (f64, f64, f64),
+}
+
+/// STABLE setup and hold constraint.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LefTimingStable {
+    /// Setup time.
+    pub setup: f64,
+    /// Hold time.
+    pub hold: f64,
+    /// Edge of the constrained pin.
+    pub rise_fall: LefRiseFall,
+}
+
+/// SDF timing check of a TIMING arc.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LefSdfTrigger {
+    /// Type of the check, for example SETUP or PERIOD.
+    pub trigger_type: String,
+    /// Edges of the from and to pin (ANYEDGE, POSEDGE or NEGEDGE). Not set for one-pin checks.
+    pub edges: Option<(String, String)>,
+    /// Size of the table.
+    pub dimension: (f64, f64, f64),
+}
+
+/// Signal transition of a TIMING statement.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LefRiseFall {
+    RISE,
+    FALL,
+}
+
+impl FromStr for LefRiseFall {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "RISE" => Ok(Self::RISE),
+            "FALL" => Ok(Self::FALL),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for LefRiseFall {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RISE => f.write_str("RISE"),
+            Self::FALL => f.write_str("FALL"),
+        }
+    }
+}
+
+/// UNATENESS of a TIMING arc.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum LefTimingUnateness {
+    /// Output changes in the opposite direction of the input.
+    INVERT,
+    /// Output changes in the same direction as the input.
+    NONINVERT,
+    /// Output change does not depend on the input direction.
+    NONUNATE,
+}
+
+impl FromStr for LefTimingUnateness {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "INVERT" => Ok(Self::INVERT),
+            "NONINVERT" => Ok(Self::NONINVERT),
+            "NONUNATE" => Ok(Self::NONUNATE),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for LefTimingUnateness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::INVERT => f.write_str("INVERT"),
+            Self::NONINVERT => f.write_str("NONINVERT"),
+            Self::NONUNATE => f.write_str("NONUNATE"),
+        }
+    }
+}
+
+/// Metal density of a layer in a MACRO.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefDensityLayer {
+    /// Name of the layer.
+    pub layer_name: String,
+    /// Rectangles with their density.
+    pub rects: Vec<LefDensityRect>,
+}
+
+/// Rectangle of a macro with the metal density inside it.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct LefDensityRect {
+    /// Corner points of the rectangle.
+    pub rect: ((f64, f64), (f64, f64)),
+    /// Density in percent.
+    pub density: f64,
 }
 
 /// PIN definition of a MACRO.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct LefMacroPin {
     /// Name of the pin.
     pub name: String,
@@ -235,12 +1206,165 @@ pub struct LefMacroPin {
     pub shape_type: Option<LefPinShape>,
     /// Name of another pin that must be connected to this pin.
     pub must_join: Option<String>,
-    /// Port 
-    pub port: Vec<LefLayerGeometries>,
+    /// All PORTs of the pin. Shapes within a port are connected, separate ports are only connected through the cell.
+    pub ports: Vec<LefPort>,
+    /// Antenna information of the pin.
+    pub antenna: LefPinAntenna,
+    /// Name of the net expression property and the default net name (NETEXPR).
+    pub net_expr: Option<LefNetExpr>,
+    /// Name of the power pin this pin is sensitive to (SUPPLYSENSITIVITY).
+    pub supply_sensitivity: Option<String>,
+    /// Name of the ground pin this pin is sensitive to (GROUNDSENSITIVITY).
+    pub ground_sensitivity: Option<String>,
+    /// Electrical data (obsolete since LEF 5.4).
+    pub electrical: LefPinElectrical,
+    /// Custom properties.
+    pub properties: HashMap<String, LefPropertyValue>,
+}
+
+/// NETEXPR of a pin, used to find the net of a global power or ground pin.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LefNetExpr {
+    /// Name of the net expression property set on the instance or its parents.
+    pub property_name: String,
+    /// Net name used if the property is not found.
+    pub default_net_name: String,
+}
+
+impl FromStr for LefNetExpr {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut words = input.trim_matches('"').split_whitespace();
+        match (words.next(), words.next(), words.next()) {
+            (Some(property_name), Some(default_net_name), None) => Ok(Self {
+                property_name: property_name.to_string(),
+                default_net_name: default_net_name.to_string(),
+            }),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Electrical data of a MACRO PIN (obsolete since LEF 5.4).
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefPinElectrical {
+    pub capacitance: Option<f64>,
+    pub resistance: Option<f64>,
+    pub leakage: Option<f64>,
+    pub power: Option<f64>,
+    pub max_load: Option<f64>,
+    pub max_delay: Option<f64>,
+    pub pulldown_res: Option<f64>,
+    pub tieoff_r: Option<f64>,
+    pub v_hi: Option<f64>,
+    pub v_lo: Option<f64>,
+    pub rise_voltage_threshold: Option<f64>,
+    pub fall_voltage_threshold: Option<f64>,
+    pub rise_threshold: Option<f64>,
+    pub fall_threshold: Option<f64>,
+    pub rise_sat_cur: Option<f64>,
+    pub fall_sat_cur: Option<f64>,
+    pub rise_slew_limit: Option<f64>,
+    pub fall_slew_limit: Option<f64>,
+    /// CURRENTSOURCE (ACTIVE or RESISTIVE).
+    pub current_source: Option<String>,
+    /// IV_TABLES `(low table, high table)`.
+    pub iv_tables: Option<(String, String)>,
+    /// INPUTNOISEMARGIN `(high, low)`.
+    pub input_noise_margin: Option<(f64, f64)>,
+    /// OUTPUTNOISEMARGIN `(high, low)`.
+    pub output_noise_margin: Option<(f64, f64)>,
+    /// OUTPUTRESISTANCE `(high, low)`.
+    pub output_resistance: Option<(f64, f64)>,
+}
+
+/// Antenna information of a MACRO PIN.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefPinAntenna {
+    /// Metal area connected to the pin (ANTENNAPARTIALMETALAREA).
+    pub partial_metal_area: Vec<LefAntennaValue>,
+    /// Metal side wall area connected to the pin (ANTENNAPARTIALMETALSIDEAREA).
+    pub partial_metal_side_area: Vec<LefAntennaValue>,
+    /// Cut area connected to the pin (ANTENNAPARTIALCUTAREA).
+    pub partial_cut_area: Vec<LefAntennaValue>,
+    /// Diffusion area connected to the pin (ANTENNADIFFAREA).
+    pub diff_area: Vec<LefAntennaValue>,
+    /// Gate areas and maximum ratios for each OXIDE.
+    pub models: Vec<LefPinAntennaModel>,
+}
+
+/// Antenna value, optionally restricted to a LAYER.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LefAntennaValue {
+    pub value: f64,
+    /// Layer the value applies to. Applies to all layers if absent.
+    pub layer: Option<String>,
+}
+
+/// Antenna model of a pin for one oxide (ANTENNAMODEL).
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefPinAntennaModel {
+    /// Name of the oxide, `OXIDE1` if not specified.
+    pub oxide: String,
+    /// Gate area connected to the pin (ANTENNAGATEAREA).
+    pub gate_area: Vec<LefAntennaValue>,
+    /// Maximum cumulative area ratio (ANTENNAMAXAREACAR).
+    pub max_area_car: Vec<LefAntennaValue>,
+    /// Maximum cumulative side area ratio (ANTENNAMAXSIDEAREACAR).
+    pub max_side_area_car: Vec<LefAntennaValue>,
+    /// Maximum cumulative cut area ratio (ANTENNAMAXCUTCAR).
+    pub max_cut_car: Vec<LefAntennaValue>,
+}
+
+/// PORT of a MACRO PIN.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefPort {
+    /// CLASS of the port.
+    pub class: Option<LefPortClass>,
+    /// Layer geometries of the port.
+    pub geometries: Vec<LefLayerGeometries>,
+    /// Vias placed in the port.
+    pub vias: Vec<LefViaInstance>,
+}
+
+/// CLASS of a PORT.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LefPortClass {
+    /// Default port (CLASS NONE).
+    Default,
+    /// Core port, for example a power ring of a block.
+    Core,
+    /// Bump port for flip-chip designs.
+    Bump,
+}
+
+impl FromStr for LefPortClass {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "NONE" => Ok(Self::Default),
+            "CORE" => Ok(Self::Core),
+            "BUMP" => Ok(Self::Bump),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for LefPortClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => f.write_str("NONE"),
+            Self::Core => f.write_str("CORE"),
+            Self::Bump => f.write_str("BUMP"),
+        }
+    }
 }
 
 /// Geometrical shapes on a named layer as used in MACRO PIN and OBS definitions.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct LefLayerGeometries {
     /// Name of the layer.
     pub layer_name: String,
@@ -252,8 +1376,79 @@ pub struct LefLayerGeometries {
     pub width: Option<f64>,
     /// Geometrical shapes (PATH, RECT, POLYGON). Together with a repetition pattern.
     pub geometries: Vec<LefGeometry>,
-    /// Specify vias to be placed with their locations.
-    pub vias: Vec<()>,
+}
+
+impl LefLayerGeometries {
+    /// Geometrical shapes with ITERATE arrays expanded.
+    pub fn expanded_geometries(&self) -> Vec<LefGeometry> {
+        self.geometries.iter().flat_map(LefGeometry::expand).collect()
+    }
+}
+
+/// Placement of a VIA in a PIN PORT or OBS.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LefViaInstance {
+    /// Name of the via definition.
+    pub via_name: String,
+    /// Location of the via origin.
+    pub location: (f64, f64),
+    /// MASK numbers of the top, cut and bottom layer.
+    pub masks: (Option<u32>, Option<u32>, Option<u32>),
+    /// Array of vias (ITERATE).
+    pub step_pattern: Option<LefStepPattern>,
+}
+
+impl LefViaInstance {
+    /// Resolve the via in the cell library, then in the technology, and get its shapes on each layer at
+    /// every placed location. The MASK numbers of the instance replace the ones of the via shapes.
+    /// Returns `None` if the via is not defined or its cut PATTERN is malformed.
+    pub fn shapes(&self, library: &LefCellLibrary, tech: &LefTechnology) -> Option<Vec<LefViaLayer>> {
+        let via = library.vias.get(&self.via_name).or_else(|| tech.vias.get(&self.via_name))?;
+
+        let (top_mask, cut_mask, bottom_mask) = self.masks;
+        let is_layer = |layer: Option<&LefLayer>, name: &str| layer.is_some_and(|layer| layer.name() == name);
+        let layer_mask = |name: &str| {
+            if is_layer(via.top_layer(tech), name) {
+                top_mask
+            } else if is_layer(via.cut_layer(tech), name) {
+                cut_mask
+            } else if is_layer(via.bottom_layer(tech), name) {
+                bottom_mask
+            } else {
+                None
+            }
+        };
+
+        let offsets: Vec<(f64, f64)> = match &self.step_pattern {
+            Some(pattern) => pattern.each_offset().collect(),
+            None => vec![(0.0, 0.0)],
+        };
+
+        let layers = via
+            .shapes()?
+            .into_iter()
+            .map(|layer| {
+                let mask = layer_mask(&layer.name);
+                LefViaLayer {
+                    shapes: offsets
+                        .iter()
+                        .flat_map(|(dx, dy)| {
+                            let offset = (self.location.0 + dx, self.location.1 + dy);
+                            layer.shapes.iter().map(move |shape| {
+                                let shape = shape.translated(offset);
+                                match mask {
+                                    Some(mask) => shape.with_mask(Some(mask)),
+                                    None => shape,
+                                }
+                            })
+                        })
+                        .collect(),
+                    name: layer.name,
+                }
+            })
+            .collect();
+        Some(layers)
+    }
 }
 
 /// Type of distance measurement
@@ -343,6 +1538,12 @@ pub enum LefSignalUse {
     Ground,
     /// Clock signal.
     Clock,
+    /// Scan chain signal.
+    Scan,
+    /// Tie-off signal.
+    Tieoff,
+    /// Data signal (obsolete).
+    Data,
 }
 
 impl FromStr for LefSignalUse {
@@ -355,6 +1556,9 @@ impl FromStr for LefSignalUse {
             "POWER" => Ok(Self::Power),
             "GROUND" => Ok(Self::Ground),
             "CLOCK" => Ok(Self::Clock),
+            "SCAN" => Ok(Self::Scan),
+            "TIEOFF" => Ok(Self::Tieoff),
+            "DATA" => Ok(Self::Data),
             _ => Err(()),
         }
     }
@@ -368,6 +1572,9 @@ impl fmt::Display for LefSignalUse {
             Self::Power => f.write_str("POWER"),
             Self::Ground => f.write_str("GROUND"),
             Self::Clock => f.write_str("CLOCK"),
+            Self::Scan => f.write_str("SCAN"),
+            Self::Tieoff => f.write_str("TIEOFF"),
+            Self::Data => f.write_str("DATA"),
         }
     }
 }
@@ -408,23 +1615,31 @@ impl fmt::Display for LefPinShape {
 
 /// Spacing rules for a routing layer.
 #[allow(missing_docs)]
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct LefSpacingRules {
     pub min_spacing: f64,
     pub spacing_type: Option<LefSpacingType>,
 }
 
 #[allow(missing_docs)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum LefSpacingType {
     Range {
         min_width: f64,
         max_width: f64,
         spacing_range_type: Option<LefSpacingRangeType>,
     },
+    /// LENGTHTHRESHOLD, spacing applies only if the parallel run length is less than `max_length`.
+    LengthThreshold {
+        max_length: f64,
+        /// Optional `(min width, max width)` range of the rule.
+        width_range: Option<(f64, f64)>,
+    },
     EndOfLine {
         eol_width: f64,
         eol_widthing: f64,
+        /// PARALLELEDGE, rule applies only if a parallel edge is found near the end of line.
+        parallel_edge: Option<LefSpacingParallelEdge>,
     },
     /// Rule applies only for two shapes of the same net.
     SameNet {
@@ -439,17 +1654,34 @@ pub enum LefSpacingType {
         min_notch_spacing: f64,
         min_notch_length: f64,
     },
+    /// AREA, rule applies only to shapes with an area smaller than `min_area`.
+    Area {
+        min_area: f64,
+    },
 }
 
 #[allow(missing_docs)]
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum LefSpacingRangeType {
     UseLengthThreshold,
-    Influence { influence_length: f64 },
+    /// INFLUENCE, with an optional `(min width, max width)` range of the stub.
+    Influence { influence_length: f64, stub_range: Option<(f64, f64)> },
+    /// Second RANGE, rule applies only if the neighbour width is in `[min_width, max_width]`.
+    Range { min_width: f64, max_width: f64 },
+}
+
+/// PARALLELEDGE qualifier of an ENDOFLINE spacing rule.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq)]
+pub struct LefSpacingParallelEdge {
+    pub par_space: f64,
+    pub par_within: f64,
+    /// TWOEDGES, rule applies only if parallel edges are found on both sides.
+    pub two_edges: bool,
 }
 
 /// SPACINGTABLE, spacing rules for a routing layer.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct LefSpacingTable {
     /// Indices of the table columns.
     pub parallel_run_lengths: Vec<f64>,
@@ -465,7 +1697,7 @@ pub struct LefSpacingTable {
 /// * MasterSlice: This is usually the first layer in the stack.
 /// * Cut: Via layer that connects the previous and next layer.
 /// * Routing: Metal wires.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum LefLayer {
     /// MASTERSLICE (poly) layer.
     MasterSlice(LefMasterSliceLayer),
@@ -489,7 +1721,7 @@ impl LefLayer {
 /// Design rules for a MASTERSLICE or OVERLAP layer.
 /// Master slice layers are usually polysilicon layers and are typically used when a MACRO has
 /// pins on the poly layer.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct LefMasterSliceLayer {
     /// Name of the masterslice layer.
     pub name: String,
@@ -502,7 +1734,7 @@ pub struct LefMasterSliceLayer {
 }
 
 /// Design rules for a CUT (via) layer.
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct LefCutLayer {
     /// Name of the cut layer.
     pub name: String,
@@ -526,11 +1758,13 @@ pub struct LefCutLayer {
     pub resistance: Option<f64>,
     /// Custom properties.
     pub properties: HashMap<String, LefPropertyValue>,
+    /// Rules parsed from the `LEF58_*` properties.
+    pub lef58_rules: Vec<Lef58Rule>,
     // TODO: Antenna rule definitions.
 }
 
 /// ENCLOSURE rules for a CUT (via) layer.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct LefEnclosureRule {
     /// Rule applies for the routing layer above.
     pub above: bool,
@@ -569,7 +1803,7 @@ impl Default for LefEnclosureRule {
 }
 
 /// SPACING rules for a CUT (via) layer.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct LefCutSpacingRule {
     /// Spacing between cuts.
     pub spacing: f64,
@@ -592,7 +1826,7 @@ impl Default for LefCutSpacingRule {
 }
 
 /// Design rules for a routing layer.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct LefRoutingLayer {
     /// Name of the routing layer.
     pub name: String,
@@ -682,6 +1916,8 @@ pub struct LefRoutingLayer {
 
     /// Custom properties.
     pub properties: HashMap<String, LefPropertyValue>,
+    /// Rules parsed from the `LEF58_*` properties.
+    pub lef58_rules: Vec<Lef58Rule>,
 }
 
 impl Default for LefRoutingLayer {
@@ -726,11 +1962,185 @@ impl Default for LefRoutingLayer {
             ac_current_density: Default::default(),
             dc_current_density: Default::default(),
             properties: Default::default(),
+            lef58_rules: Default::default(),
         }
     }
 }
 
-///
+/// Advanced rule encoded in a `LEF58_*` string property of a layer.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Lef58Rule {
+    /// LEF58_TYPE, special purpose of the layer such as `MIMCAP` or `TSV`.
+    Type(String),
+    /// LEF58_CUTCLASS
+    CutClass(Lef58CutClass),
+    /// LEF58_ENCLOSURE
+    Enclosure(Lef58EnclosureRule),
+    /// LEF58_SPACING with ENDOFLINE.
+    EndOfLineSpacing(Lef58EolSpacingRule),
+    /// LEF58_EOLEXTENSIONSPACING
+    EolExtensionSpacing(Lef58EolExtensionSpacingRule),
+    /// LEF58_MINSTEP
+    MinStep(Lef58MinStepRule),
+    /// Unknown LEF58 property, or a statement which is not modeled, kept verbatim.
+    Raw {
+        /// Name of the property, e.g. `LEF58_SPACINGTABLE`.
+        property: String,
+        /// Text of the property or of the single statement.
+        value: String,
+    },
+}
+
+/// CUTCLASS definition of a cut layer.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lef58CutClass {
+    /// Name of the cut class.
+    pub name: String,
+    /// Width of the cut.
+    pub width: f64,
+    /// Length of the cut. Equal to the width if not specified.
+    pub length: Option<f64>,
+    /// Number of cuts of a bar via.
+    pub cuts: Option<u32>,
+    /// Orientation of a rectangular cut.
+    pub orient: Option<LefRoutingDirection>,
+}
+
+/// Overhang of a LEF58_ENCLOSURE rule.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Lef58EnclosureOverhang {
+    /// `overhang1 overhang2`, on two opposite sides and on the other two sides.
+    Overhang(f64, f64),
+    /// `EOL eolWidth [MINLENGTH minLength] eolOverhang otherOverhang`
+    EndOfLine {
+        eol_width: f64,
+        min_length: Option<f64>,
+        eol_overhang: f64,
+        other_overhang: f64,
+    },
+    /// `END overhang1 SIDE overhang2`, for the line-end and the sides of a rectangular cut.
+    EndSide { end: f64, side: f64 },
+    /// `HORIZONTAL overhang1 VERTICAL overhang2`
+    HorizontalVertical { horizontal: f64, vertical: f64 },
+}
+
+/// LEF58_ENCLOSURE rule of a cut layer.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Lef58EnclosureRule {
+    /// Rule applies only to cuts of this class.
+    pub cut_class: Option<String>,
+    /// Rule applies for the routing layer above.
+    pub above: bool,
+    /// Rule applies for the routing layer below.
+    pub below: bool,
+    /// Required overhang.
+    pub overhang: Lef58EnclosureOverhang,
+    /// WIDTH, rule applies only if the wire is at least this wide.
+    pub min_width: Option<f64>,
+    /// EXCEPTEXTRACUT, WIDTH rule does not apply if another cut is within this distance.
+    pub except_extra_cut: Option<f64>,
+    /// LENGTH, rule applies only if the sum of the overhang lengths is at least this long.
+    pub min_length: Option<f64>,
+    /// EXTRACUT, rule applies only to vias with more than one cut.
+    pub extra_cut: bool,
+    /// REDUNDANTCUT, rule applies only if a redundant cut is within this distance.
+    pub redundant_cut: Option<f64>,
+    /// CONCAVECORNERS, rule applies only at this number of concave corners.
+    pub concave_corners: Option<u32>,
+}
+
+/// LEF58_SPACING ENDOFLINE rule of a routing layer.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lef58EolSpacingRule {
+    pub eol_space: f64,
+    pub eol_width: f64,
+    /// EXACTWIDTH, rule applies only to wires of exactly `eol_width`.
+    pub exact_width: bool,
+    /// WRONGDIRSPACING
+    pub wrong_dir_spacing: Option<f64>,
+    /// OPPOSITEWIDTH
+    pub opposite_width: Option<f64>,
+    pub eol_within: f64,
+    pub wrong_dir_within: Option<f64>,
+    /// SAMEMASK, rule applies only to shapes on the same mask.
+    pub same_mask: bool,
+    /// ENDPRLSPACING, `(spacing, parallel run length)`.
+    pub end_prl_spacing: Option<(f64, f64)>,
+    /// ENDTOEND
+    pub end_to_end: Option<f64>,
+    /// MAXLENGTH
+    pub max_length: Option<f64>,
+    /// MINLENGTH
+    pub min_length: Option<f64>,
+    /// TWOSIDES of MINLENGTH.
+    pub two_sides: bool,
+    /// EQUALRECTWIDTH
+    pub equal_rect_width: bool,
+    /// PARALLELEDGE
+    pub parallel_edge: Option<Lef58EolParallelEdge>,
+}
+
+/// PARALLELEDGE qualifier of a LEF58_SPACING ENDOFLINE rule.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lef58EolParallelEdge {
+    pub subtract_eol_width: bool,
+    pub par_space: f64,
+    pub par_within: f64,
+    pub prl: Option<f64>,
+    pub min_length: Option<f64>,
+    pub two_edges: bool,
+    pub same_metal: bool,
+    pub non_eol_corner_only: bool,
+    pub parallel_same_mask: bool,
+}
+
+/// LEF58_EOLEXTENSIONSPACING rule of a routing layer.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lef58EolExtensionSpacingRule {
+    /// Minimum spacing.
+    pub spacing: f64,
+    /// PARALLELONLY, rule applies only to parallel line-ends.
+    pub parallel_only: bool,
+    /// Line-end extensions, one for each ENDOFLINE width.
+    pub extensions: Vec<Lef58EolExtension>,
+}
+
+/// `ENDOFLINE eolWidth [MINLENGTH minLength] EXTENSION extension [WRONGDIRECTION wrongDirExtension]`
+#[allow(missing_docs)]
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lef58EolExtension {
+    pub eol_width: f64,
+    pub min_length: Option<f64>,
+    pub extension: f64,
+    pub wrong_direction_extension: Option<f64>,
+}
+
+/// LEF58_MINSTEP rule of a routing layer.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Lef58MinStepRule {
+    /// Minimum step length.
+    pub min_step_length: f64,
+    /// MAXEDGES, maximum number of consecutive edges shorter than the step length.
+    pub max_edges: Option<u32>,
+    /// MINADJACENTLENGTH
+    pub min_adjacent_length: Option<f64>,
+    /// CONVEXCORNER of MINADJACENTLENGTH.
+    pub convex_corner: bool,
+    /// EXCEPTWITHIN of MINADJACENTLENGTH.
+    pub except_within: Option<f64>,
+    /// NOBETWEENEOL
+    pub no_between_eol: Option<f64>,
+    /// NOADJACENTEOL
+    pub no_adjacent_eol: Option<f64>,
+    /// EXCEPTADJACENTLENGTH of NOADJACENTEOL.
+    pub except_adjacent_length: Option<f64>,
+    /// CONCAVECORNERS of NOADJACENTEOL.
+    pub concave_corners: bool,
+}
+
+/// CLASS of a macro together with its sub-class.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum LefMacroClass {
     /// Macro with fixed position.
@@ -748,21 +2158,48 @@ pub enum LefMacroClass {
     CORE(Option<LefMacroClassCoreType>),
     /// Start or end of core rows. Typically used to connect to the power grid.
     ENDCAP(Option<LefMacroClassEndcapType>),
+    /// Macro without class (obsolete).
+    NONE,
+    /// Bump cell.
+    BUMP,
+    /// Virtual macro (obsolete).
+    VIRTUAL,
+    /// Corner cell of an I/O ring (obsolete).
+    CORNER,
 }
 
 impl FromStr for LefMacroClass {
     type Err = String;
 
+    /// Parse the class with its optional sub-class, for example `CORE TIEHIGH`.
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        match input {
-            "COVER" => Ok(Self::COVER(false)),
-            "RING" => Ok(Self::RING),
-            "BLOCK" => Ok(Self::BLOCK(Default::default())),
-            "PAD" => Ok(Self::PAD(Default::default())),
-            "CORE" => Ok(Self::CORE(Default::default())),
-            "ENDCAP" => Ok(Self::ENDCAP(Default::default())),
-            _ => Err(format!("unimplment: {}", input)),
+        let err = || format!("unknown macro class '{}'", input);
+        let mut words = input.split_whitespace();
+        let class = words.next().ok_or_else(err)?;
+        let sub_class = words.next();
+        if words.next().is_some() {
+            return Err(err());
+        }
+
+        fn parse_sub_class<T: FromStr>(sub_class: Option<&str>) -> Result<Option<T>, ()> {
+            sub_class.map(T::from_str).transpose().map_err(|_| ())
         }
+
+        let class = match (class, sub_class) {
+            ("COVER", None) => Self::COVER(false),
+            ("COVER", Some("BUMP")) => Self::COVER(true),
+            ("RING", None) => Self::RING,
+            ("BLOCK", sub_class) => Self::BLOCK(parse_sub_class(sub_class).map_err(|_| err())?),
+            ("PAD", sub_class) => Self::PAD(parse_sub_class(sub_class).map_err(|_| err())?),
+            ("CORE", sub_class) => Self::CORE(parse_sub_class(sub_class).map_err(|_| err())?),
+            ("ENDCAP", sub_class) => Self::ENDCAP(parse_sub_class(sub_class).map_err(|_| err())?),
+            ("NONE", None) => Self::NONE,
+            ("BUMP", None) => Self::BUMP,
+            ("VIRTUAL", None) => Self::VIRTUAL,
+            ("CORNER", None) => Self::CORNER,
+            _ => return Err(err()),
+        };
+        Ok(class)
     }
 }
 
@@ -772,34 +2209,38 @@ impl fmt::Display for LefMacroClass {
             Self::COVER(bump) => {
                 f.write_str("COVER")?;
                 if *bump {
-                    f.write_str("BUMP")?;
+                    f.write_str(" BUMP")?;
                 }
             }
             Self::RING => f.write_str("RING")?,
             Self::BLOCK(sub_class) => {
                 f.write_str("BLOCK")?;
                 if let Some(sub_class) = sub_class {
-                    sub_class.fmt(f)?;
+                    write!(f, " {}", sub_class)?;
                 }
             }
             Self::PAD(sub_class) => {
                 f.write_str("PAD")?;
                 if let Some(sub_class) = sub_class {
-                    sub_class.fmt(f)?;
+                    write!(f, " {}", sub_class)?;
                 }
             }
             Self::CORE(sub_class) => {
                 f.write_str("CORE")?;
                 if let Some(sub_class) = sub_class {
-                    sub_class.fmt(f)?;
+                    write!(f, " {}", sub_class)?;
                 }
             }
             Self::ENDCAP(sub_class) => {
                 f.write_str("ENDCAP")?;
                 if let Some(sub_class) = sub_class {
-                    sub_class.fmt(f)?;
+                    write!(f, " {}", sub_class)?;
                 }
             }
+            Self::NONE => f.write_str("NONE")?,
+            Self::BUMP => f.write_str("BUMP")?,
+            Self::VIRTUAL => f.write_str("VIRTUAL")?,
+            Self::CORNER => f.write_str("CORNER")?,
         }
         Ok(())
     }
@@ -1013,7 +2454,7 @@ impl fmt::Display for LefMacroClassEndcapType {
 }
 
 /// Data type of a property value.
-#[derive(Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum LefPropertyType {
     /// Integer number.
     Integer,
@@ -1046,8 +2487,77 @@ impl fmt::Display for LefPropertyType {
     }
 }
 
+/// Kind of object a property is defined for.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum LefPropertyObjectType {
+    /// LIBRARY
+    Library,
+    /// COMPONENTPIN
+    ComponentPin,
+    /// PIN
+    Pin,
+    /// MACRO
+    Macro,
+    /// VIA
+    Via,
+    /// VIARULE
+    ViaRule,
+    /// LAYER
+    Layer,
+    /// NONDEFAULTRULE
+    NonDefaultRule,
+}
+
+impl FromStr for LefPropertyObjectType {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input {
+            "LIBRARY" => Ok(Self::Library),
+            "COMPONENTPIN" => Ok(Self::ComponentPin),
+            "PIN" => Ok(Self::Pin),
+            "MACRO" => Ok(Self::Macro),
+            "VIA" => Ok(Self::Via),
+            "VIARULE" => Ok(Self::ViaRule),
+            "LAYER" => Ok(Self::Layer),
+            "NONDEFAULTRULE" => Ok(Self::NonDefaultRule),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for LefPropertyObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Library => f.write_str("LIBRARY"),
+            Self::ComponentPin => f.write_str("COMPONENTPIN"),
+            Self::Pin => f.write_str("PIN"),
+            Self::Macro => f.write_str("MACRO"),
+            Self::Via => f.write_str("VIA"),
+            Self::ViaRule => f.write_str("VIARULE"),
+            Self::Layer => f.write_str("LAYER"),
+            Self::NonDefaultRule => f.write_str("NONDEFAULTRULE"),
+        }
+    }
+}
+
+/// Entry of the PROPERTYDEFINITIONS section.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LefPropertyDefinition {
+    /// Kind of object the property belongs to.
+    pub object_type: LefPropertyObjectType,
+    /// Name of the property.
+    pub name: String,
+    /// Data type of the property values.
+    pub property_type: LefPropertyType,
+    /// Allowed `(min, max)` range of numerical values.
+    pub range: Option<(f64, f64)>,
+    /// Default value.
+    pub default: Option<LefPropertyValue>,
+}
+
 /// Value of a LEF/DEF property.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum LefPropertyValue {
     /// Integer.
     Int(i32),
@@ -1138,7 +2648,7 @@ impl fmt::Display for LefSymmetry {
 
 /// Antenna rule definitions.
 /// TODO: 
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub struct LefAntennaRules {
 }
 
@@ -1255,6 +2765,7 @@ impl FromStr for LefPinDirection {
         match input {
             "INPUT" => Ok(Self::Input),
             "OUTPUT" => Ok(Self::Output(false)),
+            "OUTPUT TRISTATE" => Ok(Self::Output(true)),
             "INOUT" => Ok(Self::Inout),
             "FEEDTHRU" => Ok(Self::Feedthru),
             _ => Err(()),
@@ -1273,3 +2784,235 @@ impl fmt::Display for LefPinDirection {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(xl: f64, yl: f64, xh: f64, yh: f64, mask: Option<u32>) -> LefViaShape {
+        LefViaShape::Rect((xl, yl), (xh, yh), mask)
+    }
+
+    fn via_tech() -> LefTechnology {
+        let routing = |name: &str| LefLayer::Routing(LefRoutingLayer { name: name.to_string(), ..Default::default() });
+        let mut tech = LefTechnology {
+            layers: vec![
+                routing("metal1"),
+                LefLayer::Cut(LefCutLayer { name: "via1".to_string(), ..Default::default() }),
+                routing("metal2"),
+            ],
+            ..Default::default()
+        };
+        let via = LefVia {
+            geometry: vec![
+                LefViaLayer { name: "metal2".to_string(), shapes: vec![rect(-1.0, -1.0, 1.0, 1.0, None)] },
+                LefViaLayer { name: "via1".to_string(), shapes: vec![rect(-0.5, -0.5, 0.5, 0.5, Some(1))] },
+                LefViaLayer { name: "metal1".to_string(), shapes: vec![rect(-1.0, -1.0, 1.0, 1.0, Some(2))] },
+            ],
+            ..Default::default()
+        };
+        tech.vias.insert("V1".to_string(), via);
+        tech
+    }
+
+    #[test]
+    fn converts_database_units() {
+        let units = LefUnits { database_microns: 2000.0, ..Default::default() };
+        assert_eq!(units.to_dbu(0.19), 380);
+        assert_eq!(units.to_dbu(-0.0004), -1);
+        assert_eq!(units.from_dbu(380), 0.19);
+        assert_eq!(LefUnits::default().to_dbu(1.5), 150);
+        assert_eq!(LefUnits::default().from_dbu(150), 1.5);
+    }
+
+    #[test]
+    fn converts_values_to_database_scale() {
+        // LEF values are in ohms regardless of the factor, `RESISTANCE OHMS 10000`
+        // only means the database stores 10000 units per ohm
+        let units = LefUnits {
+            time_ns: 100.0,
+            capacitance_pf: 1000000.0,
+            resistance_ohms: 10000.0,
+            power_mw: 10000.0,
+            current_ma: 10000.0,
+            voltage_v: 1000.0,
+            database_microns: 100.0,
+            frequency_mega_hz: 10.0,
+        };
+        assert_eq!(units.resistance_to_db(0.375), 3750.0);
+        assert_eq!(units.capacitance_to_db(0.5), 500000.0);
+        assert_eq!(units.time_to_db(2.5), 250.0);
+        assert_eq!(units.power_to_db(0.5), 5000.0);
+        assert_eq!(units.current_to_db(2.0), 20000.0);
+        assert_eq!(units.voltage_to_db(1.5), 1500.0);
+        assert_eq!(units.frequency_to_db(5.0), 50.0);
+        assert_eq!(LefUnits::default().resistance_to_db(3.0), 3.0);
+    }
+
+    fn cross_talk_table() -> LefCrossTalkTable {
+        let victim = |length: f64, values: &[f64]| LefCrossTalkVictim { length, values: values.to_vec() };
+        let resistance = |output_resistances: &[f64], victims: Vec<LefCrossTalkVictim>| LefCrossTalkResistance {
+            output_resistances: output_resistances.to_vec(),
+            victims,
+        };
+        LefCrossTalkTable {
+            number: 1,
+            edges: vec![
+                LefCrossTalkEdge {
+                    edge_rate: 1.0,
+                    resistances: vec![
+                        resistance(&[10.0, 20.0], vec![victim(100.0, &[1.0, 2.0]), victim(200.0, &[3.0, 4.0])]),
+                        resistance(&[100.0], vec![victim(100.0, &[10.0])]),
+                    ],
+                },
+                LefCrossTalkEdge {
+                    edge_rate: 3.0,
+                    resistances: vec![resistance(&[10.0, 20.0], vec![victim(100.0, &[5.0, 6.0]), victim(200.0, &[7.0, 8.0])])],
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn interpolates_points() {
+        let points = [(2.0, 20.0), (0.0, 0.0), (1.0, 10.0)];
+        assert_eq!(interpolate(&points, 0.5), Some(5.0));
+        assert_eq!(interpolate(&points, 1.5), Some(15.0));
+        assert_eq!(interpolate(&points, 1.0), Some(10.0));
+        // Clamped to the first and last point
+        assert_eq!(interpolate(&points, -1.0), Some(0.0));
+        assert_eq!(interpolate(&points, 5.0), Some(20.0));
+        assert_eq!(interpolate(&[(1.0, 7.0)], 0.0), Some(7.0));
+        assert_eq!(interpolate(&[(1.0, 7.0)], 2.0), Some(7.0));
+        assert_eq!(interpolate(&[], 1.0), None);
+
+        let ir_drop = LefIrDropTable { name: "DRAM".to_string(), values: vec![(0.0, 0.0), (0.5, 0.1)] };
+        assert_eq!(ir_drop.voltage(0.25), Some(0.05));
+    }
+
+    #[test]
+    fn looks_up_cross_talk_edges() {
+        let table = cross_talk_table();
+        let edge = &table.edges[0];
+        assert_eq!(edge.lookup(10.0, 100.0), Some(1.0));
+        assert_eq!(edge.lookup(15.0, 150.0), Some(2.5));
+        // Out of range values are clamped
+        assert_eq!(edge.lookup(0.0, 300.0), Some(3.0));
+        // The OUTPUTRESISTANCE group closest to the resistance is used
+        assert_eq!(edge.lookup(40.0, 100.0), Some(2.0));
+        assert_eq!(edge.lookup(80.0, 100.0), Some(10.0));
+        assert_eq!(edge.lookup(1000.0, 100.0), Some(10.0));
+
+        let single = LefCrossTalkEdge { edge_rate: 1.0, resistances: vec![edge.resistances[1].clone()] };
+        assert_eq!(single.lookup(0.0, 0.0), Some(10.0));
+
+        let empty = LefCrossTalkEdge {
+            edge_rate: 1.0,
+            resistances: vec![LefCrossTalkResistance { output_resistances: vec![], victims: edge.resistances[0].victims.clone() }],
+        };
+        assert_eq!(empty.lookup(10.0, 100.0), None);
+        assert_eq!(LefCrossTalkEdge::default().lookup(10.0, 100.0), None);
+    }
+
+    #[test]
+    fn looks_up_cross_talk_tables() {
+        let table = cross_talk_table();
+        assert_eq!(table.lookup(1.0, 10.0, 100.0), Some(1.0));
+        assert_eq!(table.lookup(2.0, 10.0, 100.0), Some(3.0));
+        assert_eq!(table.lookup(2.0, 15.0, 150.0), Some(4.5));
+        assert_eq!(table.lookup(0.0, 20.0, 200.0), Some(4.0));
+        assert_eq!(table.lookup(10.0, 20.0, 200.0), Some(8.0));
+        assert_eq!(LefCrossTalkTable::default().lookup(1.0, 10.0, 100.0), None);
+
+        // Edges without a usable OUTPUTRESISTANCE entry are skipped
+        let mut table = table;
+        table.edges[1].resistances.clear();
+        assert_eq!(table.lookup(3.0, 10.0, 100.0), Some(1.0));
+    }
+
+    fn via_rule_params() -> LefViaRuleParams {
+        LefViaRuleParams {
+            rule_name: "M1_M2".to_string(),
+            cut_size: (0.5, 0.5),
+            layers: ("metal1".to_string(), "via1".to_string(), "metal2".to_string()),
+            cut_spacing: (0.25, 1.0),
+            enclosure: ((0.5, 0.25), (0.25, 0.5)),
+            row_col: (2, 3),
+            origin: (10.0, 20.0),
+            offset: ((0.0, 1.0), (2.0, 0.0)),
+            pattern: None,
+        }
+    }
+
+    #[test]
+    fn expands_via_rule_params() {
+        let layers = via_rule_params().shapes().unwrap();
+        let names: Vec<_> = layers.iter().map(|layer| layer.name.as_str()).collect();
+        assert_eq!(names, ["metal1", "via1", "metal2"]);
+        assert_eq!(layers[0].shapes, [rect(8.5, 19.75, 11.5, 22.25, None)]);
+        assert_eq!(
+            layers[1].shapes,
+            [
+                rect(9.0, 19.0, 9.5, 19.5, None),
+                rect(9.75, 19.0, 10.25, 19.5, None),
+                rect(10.5, 19.0, 11.0, 19.5, None),
+                rect(9.0, 20.5, 9.5, 21.0, None),
+                rect(9.75, 20.5, 10.25, 21.0, None),
+                rect(10.5, 20.5, 11.0, 21.0, None),
+            ]
+        );
+        assert_eq!(layers[2].shapes, [rect(10.75, 18.5, 13.25, 21.5, None)]);
+
+        let single = LefViaRuleParams { row_col: (1, 1), cut_spacing: (0.0, 0.0), ..via_rule_params() };
+        assert_eq!(single.shapes().unwrap()[1].shapes, [rect(9.75, 19.75, 10.25, 20.25, None)]);
+    }
+
+    #[test]
+    fn expands_via_rule_pattern() {
+        let params = LefViaRuleParams { pattern: Some("1_A_1_4".to_string()), ..via_rule_params() };
+        assert_eq!(
+            params.shapes().unwrap()[1].shapes,
+            [rect(9.0, 19.0, 9.5, 19.5, None), rect(10.5, 19.0, 11.0, 19.5, None), rect(9.75, 20.5, 10.25, 21.0, None)]
+        );
+
+        for pattern in ["1_A", "1_A_1_4_1_F", "1_G_1_4", "1_A_1_R", "1_A_1"] {
+            let params = LefViaRuleParams { pattern: Some(pattern.to_string()), ..via_rule_params() };
+            assert!(params.shapes().is_none(), "{}", pattern);
+            let via = LefVia { via_rule: Some(params), ..Default::default() };
+            assert!(via.shapes().is_none(), "{}", pattern);
+        }
+    }
+
+    #[test]
+    fn decodes_cut_pattern() {
+        let row = |bits: &str| bits.chars().map(|c| c == '1').collect::<Vec<_>>();
+        assert_eq!(
+            decode_cut_pattern("2_R2F_1_A5", 3, 8),
+            Some(vec![row("11111111"), row("11111111"), row("10100101")])
+        );
+        // Repeat counts and row counts are hexadecimal, bits beyond the columns are ignored.
+        assert_eq!(decode_cut_pattern("A_rA8", 10, 5), Some(vec![row("10001"); 10]));
+        assert_eq!(decode_cut_pattern("1_C", 1, 6), Some(vec![row("110000")]));
+        assert_eq!(decode_cut_pattern("2_F", 1, 4), None);
+        assert_eq!(decode_cut_pattern("1_R2", 1, 4), None);
+        assert_eq!(decode_cut_pattern("", 1, 4), None);
+    }
+
+    #[test]
+    fn via_instance_applies_masks() {
+        let tech = via_tech();
+        let instance = LefViaInstance {
+            via_name: "V1".to_string(),
+            location: (10.0, 20.0),
+            masks: (Some(3), None, Some(1)),
+            step_pattern: None,
+        };
+        let layers = instance.shapes(&LefCellLibrary::default(), &tech).unwrap();
+        assert_eq!(layers[0].shapes, [rect(9.0, 19.0, 11.0, 21.0, Some(3))]);
+        assert_eq!(layers[1].shapes, [rect(9.5, 19.5, 10.5, 20.5, Some(1))]);
+        assert_eq!(layers[2].shapes, [rect(9.0, 19.0, 11.0, 21.0, Some(1))]);
+
+        let unknown = LefViaInstance { via_name: "V2".to_string(), ..instance };
+        assert!(unknown.shapes(&LefCellLibrary::default(), &tech).is_none());
+    }
+}
@@ -7,6 +7,9 @@ pub enum LefReadError {
 
     #[error("{0}")]
     Msg(String),
+
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
 }
 
 pub type LefReadResult<T> = Result<T, LefReadError>; 
\ No newline at end of file
@@ -0,0 +1,456 @@
+use crate::{Lef58CutClass, Lef58EnclosureOverhang, Lef58EnclosureRule, Lef58EolExtension, Lef58EolExtensionSpacingRule, Lef58EolParallelEdge, Lef58EolSpacingRule, Lef58MinStepRule, Lef58Rule, LefPropertyValue, LefRoutingDirection};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// Parse the `LEF58_*` string properties into rules, the properties themselves are left in place.
+pub fn lef58_rules(properties: &HashMap<String, LefPropertyValue>) -> Vec<Lef58Rule> {
+    let mut names: Vec<String> = properties
+        .iter()
+        .filter(|(name, value)| name.starts_with("LEF58_") && matches!(value, LefPropertyValue::String(_)))
+        .map(|(name, _)| name.clone())
+        .collect();
+    names.sort();
+
+    let mut rules = vec![];
+    for name in names {
+        if let Some(LefPropertyValue::String(value)) = properties.get(&name) {
+            rules.extend(parse_lef58_property(&name, value));
+        }
+    }
+    rules
+}
+
+/// Parse the value of a `LEF58_*` property. Each `;` terminated statement becomes one rule,
+/// statements which can not be parsed are kept as [`Lef58Rule::Raw`].
+pub fn parse_lef58_property(name: &str, value: &str) -> Vec<Lef58Rule> {
+    let parse: fn(&mut Tokens) -> Option<Lef58Rule> = match name {
+        "LEF58_TYPE" => parse_type,
+        "LEF58_CUTCLASS" => parse_cut_class,
+        "LEF58_ENCLOSURE" => parse_enclosure,
+        "LEF58_SPACING" => parse_eol_spacing,
+        "LEF58_EOLEXTENSIONSPACING" => parse_eol_extension_spacing,
+        "LEF58_MINSTEP" => parse_min_step,
+        _ => return vec![Lef58Rule::Raw { property: name.to_string(), value: value.to_string() }],
+    };
+
+    let value = value.replace(';', " ; ");
+    let tokens: Vec<&str> = value.split_whitespace().collect();
+    tokens
+        .split(|token| *token == ";")
+        .filter(|statement| !statement.is_empty())
+        .map(|statement| {
+            let mut tokens = Tokens { tokens: statement, pos: 0 };
+            match parse(&mut tokens) {
+                Some(rule) if tokens.is_empty() => rule,
+                _ => Lef58Rule::Raw { property: name.to_string(), value: format!("{} ;", statement.join(" ")) },
+            }
+        })
+        .collect()
+}
+
+struct Tokens<'a> {
+    tokens: &'a [&'a str],
+    pos: usize,
+}
+
+impl<'a> Tokens<'a> {
+    fn peek(&self) -> Option<&'a str> {
+        self.tokens.get(self.pos).copied()
+    }
+
+    fn next(&mut self) -> Option<&'a str> {
+        let token = self.peek()?;
+        self.pos += 1;
+        Some(token)
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos >= self.tokens.len()
+    }
+
+    fn keyword(&mut self, keyword: &str) -> Option<()> {
+        if self.eat(keyword) { Some(()) } else { None }
+    }
+
+    fn eat(&mut self, keyword: &str) -> bool {
+        if self.peek() == Some(keyword) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn number(&mut self) -> Option<f64> {
+        let number = self.peek()?.parse().ok()?;
+        self.pos += 1;
+        Some(number)
+    }
+
+    fn integer(&mut self) -> Option<u32> {
+        let number = self.peek()?.parse().ok()?;
+        self.pos += 1;
+        Some(number)
+    }
+}
+
+/// `TYPE type`
+fn parse_type(tokens: &mut Tokens) -> Option<Lef58Rule> {
+    tokens.keyword("TYPE")?;
+    Some(Lef58Rule::Type(tokens.next()?.to_string()))
+}
+
+/// `CUTCLASS className WIDTH viaWidth [LENGTH viaLength] [CUTS numCut] [ORIENT {HORIZONTAL | VERTICAL}]`
+fn parse_cut_class(tokens: &mut Tokens) -> Option<Lef58Rule> {
+    tokens.keyword("CUTCLASS")?;
+    let name = tokens.next()?.to_string();
+    tokens.keyword("WIDTH")?;
+    let mut cut_class = Lef58CutClass { name, width: tokens.number()?, ..Default::default() };
+
+    while let Some(keyword) = tokens.next() {
+        match keyword {
+            "LENGTH" => cut_class.length = Some(tokens.number()?),
+            "CUTS" => cut_class.cuts = Some(tokens.integer()?),
+            "ORIENT" => cut_class.orient = Some(LefRoutingDirection::from_str(tokens.next()?).ok()?),
+            _ => return None,
+        }
+    }
+
+    Some(Lef58Rule::CutClass(cut_class))
+}
+
+/// `ENCLOSURE [CUTCLASS className] [ABOVE | BELOW] overhang [WIDTH ... | LENGTH ... | EXTRACUT | REDUNDANTCUT ... | CONCAVECORNERS ...]`
+fn parse_enclosure(tokens: &mut Tokens) -> Option<Lef58Rule> {
+    tokens.keyword("ENCLOSURE")?;
+
+    let cut_class = if tokens.eat("CUTCLASS") { Some(tokens.next()?.to_string()) } else { None };
+    let (above, below) = if tokens.eat("ABOVE") {
+        (true, false)
+    } else if tokens.eat("BELOW") {
+        (false, true)
+    } else {
+        (true, true)
+    };
+
+    let overhang = if tokens.eat("EOL") {
+        let eol_width = tokens.number()?;
+        let min_length = if tokens.eat("MINLENGTH") { Some(tokens.number()?) } else { None };
+        let eol_overhang = tokens.number()?;
+        let other_overhang = tokens.number()?;
+        Lef58EnclosureOverhang::EndOfLine { eol_width, min_length, eol_overhang, other_overhang }
+    } else if tokens.eat("END") {
+        let end = tokens.number()?;
+        tokens.keyword("SIDE")?;
+        let side = tokens.number()?;
+        Lef58EnclosureOverhang::EndSide { end, side }
+    } else if tokens.eat("HORIZONTAL") {
+        let horizontal = tokens.number()?;
+        tokens.keyword("VERTICAL")?;
+        let vertical = tokens.number()?;
+        Lef58EnclosureOverhang::HorizontalVertical { horizontal, vertical }
+    } else {
+        Lef58EnclosureOverhang::Overhang(tokens.number()?, tokens.number()?)
+    };
+
+    let mut rule = Lef58EnclosureRule {
+        cut_class,
+        above,
+        below,
+        overhang,
+        min_width: None,
+        except_extra_cut: None,
+        min_length: None,
+        extra_cut: false,
+        redundant_cut: None,
+        concave_corners: None,
+    };
+
+    while let Some(keyword) = tokens.next() {
+        match keyword {
+            "WIDTH" => rule.min_width = Some(tokens.number()?),
+            "EXCEPTEXTRACUT" if rule.min_width.is_some() => rule.except_extra_cut = Some(tokens.number()?),
+            "LENGTH" => rule.min_length = Some(tokens.number()?),
+            "EXTRACUT" => rule.extra_cut = true,
+            "REDUNDANTCUT" => rule.redundant_cut = Some(tokens.number()?),
+            "CONCAVECORNERS" => rule.concave_corners = Some(tokens.integer()?),
+            _ => return None,
+        }
+    }
+
+    Some(Lef58Rule::Enclosure(rule))
+}
+
+/// `SPACING eolSpace ENDOFLINE eolWidth [EXACTWIDTH] [WRONGDIRSPACING s] [OPPOSITEWIDTH w] WITHIN eolWithin [wrongDirWithin] ...`
+fn parse_eol_spacing(tokens: &mut Tokens) -> Option<Lef58Rule> {
+    tokens.keyword("SPACING")?;
+    let eol_space = tokens.number()?;
+    tokens.keyword("ENDOFLINE")?;
+    let eol_width = tokens.number()?;
+    let exact_width = tokens.eat("EXACTWIDTH");
+    let wrong_dir_spacing = if tokens.eat("WRONGDIRSPACING") { Some(tokens.number()?) } else { None };
+    let opposite_width = if tokens.eat("OPPOSITEWIDTH") { Some(tokens.number()?) } else { None };
+    tokens.keyword("WITHIN")?;
+    let mut rule = Lef58EolSpacingRule {
+        eol_space,
+        eol_width,
+        exact_width,
+        wrong_dir_spacing,
+        opposite_width,
+        eol_within: tokens.number()?,
+        wrong_dir_within: tokens.number(),
+        ..Default::default()
+    };
+
+    while let Some(keyword) = tokens.next() {
+        match keyword {
+            "SAMEMASK" => rule.same_mask = true,
+            "ENDPRLSPACING" => {
+                let spacing = tokens.number()?;
+                tokens.keyword("PRL")?;
+                rule.end_prl_spacing = Some((spacing, tokens.number()?));
+            }
+            "ENDTOEND" => rule.end_to_end = Some(tokens.number()?),
+            "MAXLENGTH" => rule.max_length = Some(tokens.number()?),
+            "MINLENGTH" => {
+                rule.min_length = Some(tokens.number()?);
+                rule.two_sides = tokens.eat("TWOSIDES");
+            }
+            "EQUALRECTWIDTH" => rule.equal_rect_width = true,
+            "PARALLELEDGE" => rule.parallel_edge = Some(parse_eol_parallel_edge(tokens)?),
+            _ => return None,
+        }
+    }
+
+    Some(Lef58Rule::EndOfLineSpacing(rule))
+}
+
+/// `PARALLELEDGE [SUBTRACTEOLWIDTH] parSpace WITHIN parWithin [PRL prl] [MINLENGTH minLength] [TWOEDGES] ...`
+fn parse_eol_parallel_edge(tokens: &mut Tokens) -> Option<Lef58EolParallelEdge> {
+    let subtract_eol_width = tokens.eat("SUBTRACTEOLWIDTH");
+    let par_space = tokens.number()?;
+    tokens.keyword("WITHIN")?;
+    let mut parallel_edge =
+        Lef58EolParallelEdge { subtract_eol_width, par_space, par_within: tokens.number()?, ..Default::default() };
+
+    loop {
+        if tokens.eat("PRL") {
+            parallel_edge.prl = Some(tokens.number()?);
+        } else if tokens.eat("MINLENGTH") {
+            parallel_edge.min_length = Some(tokens.number()?);
+        } else if tokens.eat("TWOEDGES") {
+            parallel_edge.two_edges = true;
+        } else if tokens.eat("SAMEMETAL") {
+            parallel_edge.same_metal = true;
+        } else if tokens.eat("NONEOLCORNERONLY") {
+            parallel_edge.non_eol_corner_only = true;
+        } else if tokens.eat("PARALLELSAMEMASK") {
+            parallel_edge.parallel_same_mask = true;
+        } else {
+            break;
+        }
+    }
+
+    Some(parallel_edge)
+}
+
+/// `EOLEXTENSIONSPACING spacing [PARALLELONLY] {ENDOFLINE eolWidth [MINLENGTH minLength] EXTENSION extension [WRONGDIRECTION ext]}...`
+fn parse_eol_extension_spacing(tokens: &mut Tokens) -> Option<Lef58Rule> {
+    tokens.keyword("EOLEXTENSIONSPACING")?;
+    let spacing = tokens.number()?;
+    let mut rule =
+        Lef58EolExtensionSpacingRule { spacing, parallel_only: tokens.eat("PARALLELONLY"), ..Default::default() };
+
+    while tokens.eat("ENDOFLINE") {
+        let eol_width = tokens.number()?;
+        let min_length = if tokens.eat("MINLENGTH") { Some(tokens.number()?) } else { None };
+        tokens.keyword("EXTENSION")?;
+        let extension = tokens.number()?;
+        let wrong_direction_extension = if tokens.eat("WRONGDIRECTION") { Some(tokens.number()?) } else { None };
+        rule.extensions.push(Lef58EolExtension { eol_width, min_length, extension, wrong_direction_extension });
+    }
+
+    if rule.extensions.is_empty() {
+        return None;
+    }
+
+    Some(Lef58Rule::EolExtensionSpacing(rule))
+}
+
+/// `MINSTEP minStepLength [MAXEDGES maxEdges] [MINADJACENTLENGTH l [CONVEXCORNER | EXCEPTWITHIN w]] [NOBETWEENEOL w | NOADJACENTEOL w ...]`
+fn parse_min_step(tokens: &mut Tokens) -> Option<Lef58Rule> {
+    tokens.keyword("MINSTEP")?;
+    let mut rule = Lef58MinStepRule { min_step_length: tokens.number()?, ..Default::default() };
+
+    while let Some(keyword) = tokens.next() {
+        match keyword {
+            "MAXEDGES" => rule.max_edges = Some(tokens.integer()?),
+            "MINADJACENTLENGTH" => rule.min_adjacent_length = Some(tokens.number()?),
+            "CONVEXCORNER" => rule.convex_corner = true,
+            "EXCEPTWITHIN" => rule.except_within = Some(tokens.number()?),
+            "NOBETWEENEOL" => rule.no_between_eol = Some(tokens.number()?),
+            "NOADJACENTEOL" => rule.no_adjacent_eol = Some(tokens.number()?),
+            "EXCEPTADJACENTLENGTH" => rule.except_adjacent_length = Some(tokens.number()?),
+            "CONCAVECORNERS" => rule.concave_corners = true,
+            _ => return None,
+        }
+    }
+
+    Some(Lef58Rule::MinStep(rule))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(name: &str, value: &str) -> Vec<Lef58Rule> {
+        parse_lef58_property(name, value)
+    }
+
+    #[test]
+    fn parses_eol_spacing() {
+        let rules = parse(
+            "LEF58_SPACING",
+            "SPACING 0.07 ENDOFLINE 0.06 EXACTWIDTH WITHIN 0.025 0.03 SAMEMASK ENDTOEND 0.08 \
+             PARALLELEDGE SUBTRACTEOLWIDTH 0.06 WITHIN 0.12 PRL 0.01 TWOEDGES ;",
+        );
+        let expected = Lef58EolSpacingRule {
+            eol_space: 0.07,
+            eol_width: 0.06,
+            exact_width: true,
+            eol_within: 0.025,
+            wrong_dir_within: Some(0.03),
+            same_mask: true,
+            end_to_end: Some(0.08),
+            parallel_edge: Some(Lef58EolParallelEdge {
+                subtract_eol_width: true,
+                par_space: 0.06,
+                par_within: 0.12,
+                prl: Some(0.01),
+                two_edges: true,
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(rules, [Lef58Rule::EndOfLineSpacing(expected)]);
+    }
+
+    #[test]
+    fn parses_eol_extension_spacing() {
+        let rules = parse(
+            "LEF58_EOLEXTENSIONSPACING",
+            "EOLEXTENSIONSPACING 0.07 ENDOFLINE 0.05 EXTENSION 0.03 ENDOFLINE 0.06 MINLENGTH 0.1 EXTENSION 0.02 WRONGDIRECTION 0.01 ;",
+        );
+        let expected = Lef58EolExtensionSpacingRule {
+            spacing: 0.07,
+            parallel_only: false,
+            extensions: vec![
+                Lef58EolExtension { eol_width: 0.05, min_length: None, extension: 0.03, wrong_direction_extension: None },
+                Lef58EolExtension { eol_width: 0.06, min_length: Some(0.1), extension: 0.02, wrong_direction_extension: Some(0.01) },
+            ],
+        };
+        assert_eq!(rules, [Lef58Rule::EolExtensionSpacing(expected)]);
+    }
+
+    #[test]
+    fn parses_cut_classes() {
+        let rules = parse("LEF58_CUTCLASS", "CUTCLASS VSINGLECUT WIDTH 0.05 ;\n CUTCLASS VBAR WIDTH 0.05 LENGTH 0.13 CUTS 2 ORIENT VERTICAL ;");
+        assert_eq!(
+            rules,
+            [
+                Lef58Rule::CutClass(Lef58CutClass { name: "VSINGLECUT".to_string(), width: 0.05, ..Default::default() }),
+                Lef58Rule::CutClass(Lef58CutClass {
+                    name: "VBAR".to_string(),
+                    width: 0.05,
+                    length: Some(0.13),
+                    cuts: Some(2),
+                    orient: Some(LefRoutingDirection::Vertical),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_enclosures() {
+        let rules = parse(
+            "LEF58_ENCLOSURE",
+            "ENCLOSURE CUTCLASS VSINGLECUT ABOVE 0.0 0.035 WIDTH 0.1 EXCEPTEXTRACUT 0.2 ;\
+             ENCLOSURE BELOW EOL 0.06 0.03 0.01 ;\
+             ENCLOSURE END 0.04 SIDE 0.01 EXTRACUT ;",
+        );
+        assert_eq!(rules.len(), 3);
+        let Lef58Rule::Enclosure(rule) = &rules[0] else { panic!("{:?}", rules[0]) };
+        assert_eq!(rule.cut_class.as_deref(), Some("VSINGLECUT"));
+        assert!(rule.above && !rule.below);
+        assert_eq!(rule.overhang, Lef58EnclosureOverhang::Overhang(0.0, 0.035));
+        assert_eq!((rule.min_width, rule.except_extra_cut), (Some(0.1), Some(0.2)));
+        let Lef58Rule::Enclosure(rule) = &rules[1] else { panic!("{:?}", rules[1]) };
+        assert!(!rule.above && rule.below);
+        assert_eq!(
+            rule.overhang,
+            Lef58EnclosureOverhang::EndOfLine { eol_width: 0.06, min_length: None, eol_overhang: 0.03, other_overhang: 0.01 }
+        );
+        let Lef58Rule::Enclosure(rule) = &rules[2] else { panic!("{:?}", rules[2]) };
+        assert!(rule.above && rule.below && rule.extra_cut);
+        assert_eq!(rule.overhang, Lef58EnclosureOverhang::EndSide { end: 0.04, side: 0.01 });
+    }
+
+    #[test]
+    fn parses_type() {
+        assert_eq!(parse("LEF58_TYPE", "TYPE MIMCAP ;"), [Lef58Rule::Type("MIMCAP".to_string())]);
+    }
+
+    #[test]
+    fn parses_min_step() {
+        let rules = parse("LEF58_MINSTEP", "MINSTEP 0.05 MAXEDGES 1 MINADJACENTLENGTH 0.065 CONVEXCORNER ;");
+        let expected = Lef58MinStepRule {
+            min_step_length: 0.05,
+            max_edges: Some(1),
+            min_adjacent_length: Some(0.065),
+            convex_corner: true,
+            ..Default::default()
+        };
+        assert_eq!(rules, [Lef58Rule::MinStep(expected)]);
+    }
+
+    #[test]
+    fn keeps_unknown_properties_raw() {
+        let value = "SPACINGTABLE PARALLELRUNLENGTH 0 WIDTH 0 0.05 ;";
+        assert_eq!(
+            parse("LEF58_SPACINGTABLE", value),
+            [Lef58Rule::Raw { property: "LEF58_SPACINGTABLE".to_string(), value: value.to_string() }]
+        );
+    }
+
+    #[test]
+    fn keeps_malformed_statements_raw() {
+        let rules = parse("LEF58_MINSTEP", "MINSTEP 0.05 ; MINSTEP abc ; MINSTEP 0.05 BOGUS 1 ;");
+        assert_eq!(
+            rules,
+            [
+                Lef58Rule::MinStep(Lef58MinStepRule { min_step_length: 0.05, ..Default::default() }),
+                Lef58Rule::Raw { property: "LEF58_MINSTEP".to_string(), value: "MINSTEP abc ;".to_string() },
+                Lef58Rule::Raw { property: "LEF58_MINSTEP".to_string(), value: "MINSTEP 0.05 BOGUS 1 ;".to_string() },
+            ]
+        );
+        let rules = parse("LEF58_SPACING", "SPACING 0.07 ENDOFLINE 0.06 ;");
+        assert!(matches!(&rules[..], [Lef58Rule::Raw { .. }]));
+    }
+
+    #[test]
+    fn keeps_properties_in_place() {
+        let mut properties = HashMap::new();
+        properties.insert("LEF58_TYPE".to_string(), LefPropertyValue::String("TYPE TSV ;".to_string()));
+        properties.insert("LEF58_MINSTEP".to_string(), LefPropertyValue::String("MINSTEP 0.05 ;".to_string()));
+        properties.insert("OTHER".to_string(), LefPropertyValue::String("TYPE X ;".to_string()));
+
+        let rules = lef58_rules(&properties);
+        assert_eq!(properties.len(), 3);
+        assert_eq!(
+            rules,
+            [
+                Lef58Rule::MinStep(Lef58MinStepRule { min_step_length: 0.05, ..Default::default() }),
+                Lef58Rule::Type("TSV".to_string()),
+            ]
+        );
+    }
+}
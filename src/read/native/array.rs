@@ -0,0 +1,81 @@
+use crate::{LefArray, LefFloorPlan, LefGcellPattern, LefTrackPattern};
+use super::{LefParser, LefReadResult};
+
+impl LefParser {
+    pub(super) fn parse_array(&mut self) -> LefReadResult<()> {
+        let mut array = LefArray { name: self.name()?, ..Default::default() };
+
+        while !self.at("END") {
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "SITE" | "CANPLACE" | "CANNOTOCCUPY" => {
+                    let site = self.parse_site_pattern()?;
+                    self.end_statement()?;
+                    match keyword.as_str() {
+                        "SITE" => array.sites.push(site),
+                        "CANPLACE" => array.can_place.push(site),
+                        _ => array.cannot_occupy.push(site),
+                    }
+                }
+                "TRACKS" => {
+                    let name = self.keyword()?;
+                    let start = self.number()?;
+                    self.expect("DO")?;
+                    let num_tracks = self.number()? as u32;
+                    self.expect("STEP")?;
+                    let space = self.number()?;
+                    let mut track = LefTrackPattern { name, start, num_tracks, space, ..Default::default() };
+                    if self.accept("LAYER") {
+                        while !self.at(";") {
+                            track.layers.push(self.name()?);
+                        }
+                    }
+                    self.end_statement()?;
+                    array.tracks.push(track);
+                }
+                "GCELLGRID" => {
+                    let name = self.keyword()?;
+                    let start = self.number()?;
+                    self.expect("DO")?;
+                    let num_crs = self.number()? as u32;
+                    self.expect("STEP")?;
+                    let space = self.number()?;
+                    self.end_statement()?;
+                    array.gcell_grids.push(LefGcellPattern { name, start, num_crs, space });
+                }
+                "DEFAULTCAP" => {
+                    self.number()?;
+                    while !self.accept("END") {
+                        self.expect("MINPINS")?;
+                        let min_pins = self.number()? as u32;
+                        self.expect("WIRECAP")?;
+                        let cap = self.number()?;
+                        self.end_statement()?;
+                        array.default_caps.push((min_pins, cap));
+                    }
+                    self.expect("DEFAULTCAP")?;
+                }
+                "FLOORPLAN" => {
+                    let mut floor_plan = LefFloorPlan { name: self.name()?, ..Default::default() };
+                    while !self.at("END") {
+                        let site_type = self.keyword()?;
+                        let site = self.parse_site_pattern()?;
+                        self.end_statement()?;
+                        match site_type.as_str() {
+                            "CANPLACE" => floor_plan.can_place.push(site),
+                            "CANNOTOCCUPY" => floor_plan.cannot_occupy.push(site),
+                            _ => return Err(self.error(format!("unknown site type '{}' in FLOORPLAN '{}'", site_type, floor_plan.name))),
+                        }
+                    }
+                    self.expect_end(&floor_plan.name)?;
+                    array.floor_plans.push(floor_plan);
+                }
+                _ => return Err(self.error(format!("unknown ARRAY statement '{}'", keyword))),
+            }
+        }
+        self.expect_end(&array.name)?;
+
+        self.tech.arrays.insert(array.name.clone(), array);
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{LefCrossTalkEdge, LefCrossTalkResistance, LefCrossTalkTable, LefCrossTalkVictim, LefIrDropTable, LefNoiseMargin};
+use super::{LefParser, LefReadResult};
+
+impl LefParser {
+    pub(super) fn parse_ir_drop(&mut self) -> LefReadResult<()> {
+        let mut tables = vec![];
+        while !self.accept("END") {
+            self.expect("TABLE")?;
+            let mut table = LefIrDropTable { name: self.name()?, ..Default::default() };
+            while !self.accept(";") {
+                table.values.push((self.number()?, self.number()?));
+            }
+            tables.push(table);
+        }
+        self.expect("IRDROP")?;
+
+        if self.version < 5.4 {
+            self.tech.ir_drop_tables.extend(tables);
+        }
+        Ok(())
+    }
+
+    pub(super) fn parse_noise_margin(&mut self) -> LefReadResult<()> {
+        let (low, high) = (self.number()?, self.number()?);
+        self.end_statement()?;
+        if self.version < 5.4 {
+            self.tech.noise_margin = Some(LefNoiseMargin { high, low });
+        }
+        Ok(())
+    }
+
+    pub(super) fn parse_edge_rate(&mut self, keyword: &str) -> LefReadResult<()> {
+        let number = self.number()?;
+        self.end_statement()?;
+        if self.version < 5.4 {
+            let field = match keyword {
+                "EDGERATETHRESHOLD1" => &mut self.tech.edge_rate_threshold1,
+                "EDGERATETHRESHOLD2" => &mut self.tech.edge_rate_threshold2,
+                _ => &mut self.tech.edge_rate_scale_factor,
+            };
+            *field = Some(number);
+        }
+        Ok(())
+    }
+
+    /// Read a NOISETABLE or CORRECTIONTABLE, which only differ by the name of the victim values.
+    pub(super) fn parse_cross_talk_table(&mut self, keyword: &str) -> LefReadResult<()> {
+        let values_keyword = if keyword == "NOISETABLE" { "VICTIMNOISE" } else { "CORRECTIONFACTOR" };
+
+        let mut table = LefCrossTalkTable { number: self.number()? as i32, ..Default::default() };
+        self.end_statement()?;
+
+        while !self.accept("END") {
+            let statement = self.keyword()?;
+            match statement.as_str() {
+                "EDGERATE" => {
+                    let edge = LefCrossTalkEdge { edge_rate: self.number()?, ..Default::default() };
+                    self.end_statement()?;
+                    table.edges.push(edge);
+                }
+                "OUTPUTRESISTANCE" => {
+                    let resistance = LefCrossTalkResistance { output_resistances: self.numbers_until_semicolon()?, ..Default::default() };
+                    let Some(edge) = table.edges.last_mut() else {
+                        return Err(self.error("OUTPUTRESISTANCE before EDGERATE"));
+                    };
+                    edge.resistances.push(resistance);
+                }
+                "VICTIMLENGTH" => {
+                    let length = self.number()?;
+                    self.end_statement()?;
+                    self.expect(values_keyword)?;
+                    let victim = LefCrossTalkVictim { length, values: self.numbers_until_semicolon()? };
+                    let Some(resistance) = table.edges.last_mut().and_then(|edge| edge.resistances.last_mut()) else {
+                        return Err(self.error("VICTIMLENGTH before OUTPUTRESISTANCE"));
+                    };
+                    resistance.victims.push(victim);
+                }
+                _ => return Err(self.error(format!("unknown {} statement '{}'", keyword, statement))),
+            }
+        }
+        self.expect(keyword)?;
+        self.accept(";");
+
+        if self.version < 5.4 {
+            match keyword {
+                "NOISETABLE" => self.tech.noise_table = Some(table),
+                _ => self.tech.correction_table = Some(table),
+            }
+        }
+        Ok(())
+    }
+}
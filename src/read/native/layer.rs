@@ -0,0 +1,280 @@
+use crate::{LefCutLayer, LefCutSpacingRule, LefLayer, LefPropertyObjectType, LefRoutingDirection, LefRoutingLayer, LefSpacingParallelEdge, LefSpacingRangeType, LefSpacingRules, LefSpacingTable, LefSpacingType};
+use crate::read::lef58;
+use super::{LefParser, LefReadResult};
+use std::collections::HashMap;
+use std::str::FromStr;
+
+/// SPACING statement of a routing or cut layer.
+struct SpacingStatement {
+    spacing: f64,
+    center_to_center: bool,
+    same_net: bool,
+    spacing_type: Option<LefSpacingType>,
+}
+
+impl LefParser {
+    pub(super) fn parse_layer(&mut self) -> LefReadResult<()> {
+        let name = self.name()?;
+        let mut layer_type = String::new();
+        let mut routing = LefRoutingLayer { name: name.clone(), ..Default::default() };
+        let mut cut = LefCutLayer { name: name.clone(), ..Default::default() };
+        let mut spacings = vec![];
+        let mut spacing_tables = vec![];
+        let mut resistance = None;
+        let mut resistance_per_cut = None;
+        let mut properties = HashMap::new();
+
+        while !self.at("END") {
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "TYPE" => {
+                    layer_type = self.keyword()?;
+                    self.skip_statement()?;
+                }
+                "MASK" => {
+                    let mask = self.number()? as u32;
+                    self.end_statement()?;
+                    if self.version >= 5.8 {
+                        routing.mask_num = Some(mask);
+                        cut.mask_num = Some(mask);
+                    }
+                }
+                "WIDTH" => {
+                    let width = self.number()?;
+                    self.end_statement()?;
+                    routing.width = width;
+                    cut.width = Some(width);
+                }
+                "CAPMULTIPLIER" => routing.cap_multiplier = self.number_statement()? as u32,
+                "DIAGWIDTH" => routing.diag_width = Some(self.number_statement()?),
+                "DIAGSPACING" => routing.diag_spacing = Some(self.number_statement()?),
+                "DIAGMINEDGELENGTH" => {
+                    let length = self.number_statement()?;
+                    if self.version >= 5.6 {
+                        routing.diag_min_edge_length = Some(length);
+                    }
+                }
+                "AREA" => routing.min_area = Some(self.number_statement()?),
+                "MAXWIDTH" => routing.max_width = Some(self.number_statement()?),
+                "MINWIDTH" => routing.min_width = Some(self.number_statement()?),
+                "HEIGHT" => routing.height = Some(self.number_statement()?),
+                "THICKNESS" => routing.thickness = Some(self.number_statement()?),
+                "EDGECAPACITANCE" => routing.edge_capacitance = Some(self.number_statement()?),
+                "SHRINKAGE" => routing.shrinkage = Some(self.number_statement()?),
+                "MINIMUMDENSITY" => routing.minimum_density = Some(self.number_statement()?),
+                "MAXIMUMDENSITY" => routing.maximum_density = Some(self.number_statement()?),
+                "DENSITYCHECKSTEP" => routing.density_check_step = Some(self.number_statement()?),
+                "FILLACTIVESPACING" => routing.fill_active_spacing = Some(self.number_statement()?),
+                "WIREEXTENSION" => routing.wire_extension = Some(self.number_statement()?),
+                "RESISTANCE" => {
+                    if self.accept("RPERSQ") {
+                        resistance = Some(self.number_statement()?);
+                    } else {
+                        let value = self.number_statement()?;
+                        if self.version >= 5.6 {
+                            resistance_per_cut = Some(value);
+                        }
+                    }
+                }
+                "CAPACITANCE" => {
+                    self.expect("CPERSQDIST")?;
+                    routing.capacitance = Some(self.number_statement()?);
+                }
+                "DIRECTION" => {
+                    let direction = self.keyword()?;
+                    routing.direction = LefRoutingDirection::from_str(&direction)
+                        .map_err(|_| self.error(format!("unknown routing direction '{}'", direction)))?;
+                    self.end_statement()?;
+                }
+                "PITCH" => routing.pitch = self.xy_statement()?,
+                "DIAGPITCH" => routing.diag_pitch = Some(self.xy_statement()?),
+                "OFFSET" => routing.offset = Some(self.xy_statement()?),
+                "MINSIZE" => routing.min_size = self.points_until_semicolon()?,
+                "SPACING" => spacings.push(self.parse_spacing()?),
+                "SPACINGTABLE" => {
+                    if self.accept("ORTHOGONAL") {
+                        self.skip_statement()?;
+                    } else {
+                        spacing_tables.push(self.parse_spacing_table()?);
+                    }
+                }
+                "PROPERTY" => self.parse_properties(LefPropertyObjectType::Layer, &mut properties)?,
+                "ACCURRENTDENSITY" | "DCCURRENTDENSITY" => {
+                    // Either a single value or a table ending with TABLEENTRIES
+                    self.keyword()?;
+                    if self.at_number() {
+                        self.number_statement()?;
+                    } else {
+                        while self.keyword()? != "TABLEENTRIES" {
+                            self.skip_statement()?;
+                        }
+                        self.skip_statement()?;
+                    }
+                }
+                _ => self.skip_statement()?,
+            }
+        }
+        self.expect_end(&name)?;
+
+        match layer_type.as_str() {
+            "ROUTING" => {
+                routing.resistance = resistance.or(resistance_per_cut);
+                routing.spacing = spacings
+                    .into_iter()
+                    .map(|spacing| LefSpacingRules { min_spacing: spacing.spacing, spacing_type: spacing.spacing_type })
+                    .collect();
+                // Only a single PARALLELRUNLENGTH table is modeled
+                if spacing_tables.len() == 1 {
+                    routing.spacing_table = spacing_tables.pop().flatten();
+                }
+                routing.properties = properties;
+                routing.lef58_rules = lef58::lef58_rules(&routing.properties);
+                self.tech.layers.push(LefLayer::Routing(routing));
+            }
+            "CUT" => {
+                cut.resistance = resistance.or(resistance_per_cut);
+                cut.spacing = spacings
+                    .into_iter()
+                    .map(|spacing| LefCutSpacingRule {
+                        spacing: spacing.spacing,
+                        center_to_center: spacing.center_to_center,
+                        same_net: spacing.same_net,
+                    })
+                    .collect();
+                cut.properties = properties;
+                cut.lef58_rules = lef58::lef58_rules(&cut.properties);
+                self.tech.layers.push(LefLayer::Cut(cut));
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    pub(super) fn number_statement(&mut self) -> LefReadResult<f64> {
+        let number = self.number()?;
+        self.end_statement()?;
+        Ok(number)
+    }
+
+    /// Read a statement with one value for x and y, or two separate values.
+    fn xy_statement(&mut self) -> LefReadResult<(f64, f64)> {
+        let x = self.number()?;
+        let y = if self.at_number() { self.number()? } else { x };
+        self.end_statement()?;
+        Ok((x, y))
+    }
+
+    fn parse_spacing(&mut self) -> LefReadResult<SpacingStatement> {
+        let spacing = self.number()?;
+        let mut center_to_center = false;
+        let mut same_net = None;
+        let mut range = None;
+        let mut length_threshold = None;
+        let mut end_of_line = None;
+        let mut notch_length = None;
+        let mut end_of_notch_width = None;
+        let mut area = None;
+
+        while !self.accept(";") {
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "CENTERTOCENTER" => center_to_center = true,
+                "SAMENET" => same_net = Some(self.accept("PGONLY")),
+                "PARALLELOVERLAP" => {}
+                "LAYER" => {
+                    self.name()?;
+                    self.accept("STACK");
+                }
+                "ADJACENTCUTS" => {
+                    self.number()?;
+                    self.expect("WITHIN")?;
+                    self.number()?;
+                    self.accept("EXCEPTSAMEPGNET");
+                }
+                "AREA" => area = Some(self.number()?),
+                "RANGE" => {
+                    let (min_width, max_width) = (self.number()?, self.number()?);
+                    let spacing_range_type = if self.accept("USELENGTHTHRESHOLD") {
+                        Some(LefSpacingRangeType::UseLengthThreshold)
+                    } else if self.accept("INFLUENCE") {
+                        let influence_length = self.number()?;
+                        let stub_range = if self.accept("RANGE") { Some((self.number()?, self.number()?)) } else { None };
+                        Some(LefSpacingRangeType::Influence { influence_length, stub_range })
+                    } else if self.accept("RANGE") {
+                        Some(LefSpacingRangeType::Range { min_width: self.number()?, max_width: self.number()? })
+                    } else {
+                        None
+                    };
+                    range = Some(LefSpacingType::Range { min_width, max_width, spacing_range_type });
+                }
+                "LENGTHTHRESHOLD" => {
+                    let max_length = self.number()?;
+                    let width_range = if self.accept("RANGE") { Some((self.number()?, self.number()?)) } else { None };
+                    length_threshold = Some(LefSpacingType::LengthThreshold { max_length, width_range });
+                }
+                "ENDOFLINE" => {
+                    let eol_width = self.number()?;
+                    self.expect("WITHIN")?;
+                    let eol_widthing = self.number()?;
+                    let parallel_edge = if self.accept("PARALLELEDGE") {
+                        let par_space = self.number()?;
+                        self.expect("WITHIN")?;
+                        let par_within = self.number()?;
+                        let two_edges = self.accept("TWOEDGES");
+                        Some(LefSpacingParallelEdge { par_space, par_within, two_edges })
+                    } else {
+                        None
+                    };
+                    end_of_line = Some(LefSpacingType::EndOfLine { eol_width, eol_widthing, parallel_edge });
+                }
+                "NOTCHLENGTH" => notch_length = Some(LefSpacingType::NotchLength { min_notch_length: self.number()? }),
+                "ENDOFNOTCHWIDTH" => {
+                    let end_of_notch_width_value = self.number()?;
+                    self.expect("NOTCHSPACING")?;
+                    let min_notch_spacing = self.number()?;
+                    self.expect("NOTCHLENGTH")?;
+                    let min_notch_length = self.number()?;
+                    end_of_notch_width = Some(LefSpacingType::EndOfNotchWidth {
+                        end_of_notch_width: end_of_notch_width_value,
+                        min_notch_spacing,
+                        min_notch_length,
+                    });
+                }
+                _ => return Err(self.error(format!("unknown SPACING keyword '{}'", keyword))),
+            }
+        }
+
+        let spacing_type = range
+            .or(length_threshold)
+            .or(end_of_line)
+            .or(same_net.map(|power_ground_only| LefSpacingType::SameNet { power_ground_only }))
+            .or(notch_length)
+            .or(end_of_notch_width)
+            .or(area.map(|min_area| LefSpacingType::Area { min_area }));
+
+        Ok(SpacingStatement { spacing, center_to_center, same_net: same_net.is_some(), spacing_type })
+    }
+
+    /// Read a SPACINGTABLE. Only PARALLELRUNLENGTH tables are modeled, others give `None`.
+    fn parse_spacing_table(&mut self) -> LefReadResult<Option<LefSpacingTable>> {
+        if !self.accept("PARALLELRUNLENGTH") {
+            self.skip_statement()?;
+            return Ok(None);
+        }
+
+        let mut table = LefSpacingTable::default();
+        while self.at_number() {
+            table.parallel_run_lengths.push(self.number()?);
+        }
+        while self.accept("WIDTH") {
+            table.widths.push(self.number()?);
+            let mut spacings = vec![];
+            while self.at_number() {
+                spacings.push(self.number()?);
+            }
+            table.spacings.push(spacings);
+        }
+        self.end_statement()?;
+        Ok(Some(table))
+    }
+}
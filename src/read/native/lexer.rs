@@ -0,0 +1,206 @@
+use super::super::{LefReadError, LefReadResult};
+
+/// Kind of a LEF token.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum TokenKind {
+    /// Keyword, name, number or punctuation.
+    Word,
+    /// Quoted string, without the quotes.
+    QString,
+    /// Text following BEGINEXT up to and including ENDEXT.
+    Extension,
+}
+
+/// Token with the line it starts on.
+#[derive(Clone, Debug)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub text: String,
+    pub line: usize,
+}
+
+/// Split a LEF file into tokens like the Si2 lexer does.
+///
+/// Tokens are separated by whitespace: `;`, `(` and `)` are only punctuation when
+/// they stand alone. Comments start with `#` at the beginning of a token.
+/// HISTORY statements are dropped.
+pub fn tokenize(input: &str) -> LefReadResult<Vec<Token>> {
+    let mut lexer = Lexer { chars: input.chars().collect(), pos: 0, line: 1 };
+    let mut tokens = vec![];
+
+    while let Some(c) = lexer.skip_whitespace() {
+        let line = lexer.line;
+        if c == '#' {
+            lexer.skip_line();
+        } else if c == '"' {
+            let text = lexer.quoted_string()?;
+            tokens.push(Token { kind: TokenKind::QString, text, line });
+        } else {
+            let text = lexer.word();
+            if text.eq_ignore_ascii_case("BEGINEXT") {
+                let text = lexer.raw_until_endext()?;
+                tokens.push(Token { kind: TokenKind::Extension, text, line });
+            } else if text.eq_ignore_ascii_case("HISTORY") {
+                lexer.skip_history();
+            } else {
+                tokens.push(Token { kind: TokenKind::Word, text, line });
+            }
+        }
+    }
+
+    Ok(tokens)
+}
+
+struct Lexer {
+    chars: Vec<char>,
+    pos: usize,
+    line: usize,
+}
+
+impl Lexer {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+        }
+        Some(c)
+    }
+
+    /// Skip whitespace and return the next character without consuming it.
+    fn skip_whitespace(&mut self) -> Option<char> {
+        while let Some(c) = self.peek() {
+            if !is_whitespace(c) {
+                return Some(c);
+            }
+            self.bump();
+        }
+        None
+    }
+
+    fn skip_line(&mut self) {
+        while let Some(c) = self.bump() {
+            if c == '\n' {
+                break;
+            }
+        }
+    }
+
+    fn word(&mut self) -> String {
+        let mut text = String::new();
+        while let Some(c) = self.peek() {
+            if is_whitespace(c) {
+                break;
+            }
+            text.push(c);
+            self.bump();
+        }
+        text
+    }
+
+    /// Read a quoted string, `\` escapes the next character.
+    fn quoted_string(&mut self) -> LefReadResult<String> {
+        let line = self.line;
+        self.bump();
+        let mut text = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(text),
+                Some('\\') => match self.bump() {
+                    Some(c) => text.push(c),
+                    None => break,
+                },
+                Some(c) => text.push(c),
+                None => break,
+            }
+        }
+        Err(LefReadError::Msg(format!("line {}: unterminated string", line)))
+    }
+
+    fn raw_until_endext(&mut self) -> LefReadResult<String> {
+        let line = self.line;
+        let rest: String = self.chars[self.pos..].iter().collect();
+        let Some(end) = rest.find("ENDEXT") else {
+            return Err(LefReadError::Msg(format!("line {}: BEGINEXT without ENDEXT", line)));
+        };
+        let text = &rest[..end + "ENDEXT".len()];
+        for _ in 0..text.chars().count() {
+            self.bump();
+        }
+        Ok(text.to_string())
+    }
+
+    /// Skip the text of a HISTORY statement up to a `;` following whitespace.
+    fn skip_history(&mut self) {
+        let mut after_whitespace = false;
+        while let Some(c) = self.bump() {
+            if c == ';' && after_whitespace {
+                break;
+            }
+            after_whitespace = is_whitespace(c);
+        }
+    }
+}
+
+fn is_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\r')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(input: &str) -> Vec<(TokenKind, String)> {
+        tokenize(input).unwrap().into_iter().map(|token| (token.kind, token.text)).collect()
+    }
+
+    #[test]
+    fn splits_on_whitespace_only() {
+        let tokens = texts("RECT ( 0 0.5 ) ( 1 2 ) ;\n  metal1;");
+        let words: Vec<_> = tokens.iter().map(|(_, text)| text.as_str()).collect();
+        assert_eq!(words, ["RECT", "(", "0", "0.5", ")", "(", "1", "2", ")", ";", "metal1;"]);
+        assert!(tokens.iter().all(|(kind, _)| *kind == TokenKind::Word));
+    }
+
+    #[test]
+    fn reads_quoted_strings() {
+        let tokens = texts(r#"BUSBITCHARS "[]" ; NETEXPR "VDD \"a b\"" ;"#);
+        assert_eq!(tokens[1], (TokenKind::QString, "[]".to_string()));
+        assert_eq!(tokens[4], (TokenKind::QString, r#"VDD "a b""#.to_string()));
+        assert!(tokenize("\"never closed").is_err());
+    }
+
+    #[test]
+    fn skips_comments() {
+        let tokens = texts("# header\nVERSION 5.8 ; # trailing\n  #indented\nEND LIBRARY");
+        let words: Vec<_> = tokens.iter().map(|(_, text)| text.as_str()).collect();
+        assert_eq!(words, ["VERSION", "5.8", ";", "END", "LIBRARY"]);
+    }
+
+    #[test]
+    fn drops_history() {
+        let tokens = texts("HISTORY a;b ; c ;\nVERSION 5.8 ;");
+        let words: Vec<_> = tokens.iter().map(|(_, text)| text.as_str()).collect();
+        assert_eq!(words, ["c", ";", "VERSION", "5.8", ";"]);
+    }
+
+    #[test]
+    fn keeps_extensions_raw() {
+        let tokens = tokenize("BEGINEXT \"tag\"\n  any ; text # kept\nENDEXT\nEND LIBRARY").unwrap();
+        assert_eq!(tokens[0].kind, TokenKind::Extension);
+        assert_eq!(tokens[0].text, " \"tag\"\n  any ; text # kept\nENDEXT");
+        assert_eq!((tokens[1].text.as_str(), tokens[1].line), ("END", 4));
+        assert!(tokenize("BEGINEXT \"tag\" never ended").is_err());
+    }
+
+    #[test]
+    fn tracks_lines() {
+        let tokens = tokenize("A\n\n\"multi\nline\" B\nC").unwrap();
+        let lines: Vec<_> = tokens.iter().map(|token| token.line).collect();
+        assert_eq!(lines, [1, 3, 4, 5]);
+    }
+}
@@ -0,0 +1,422 @@
+use crate::{LefAntennaValue, LefDensityLayer, LefDensityRect, LefForeign, LefGeometry, LefLayerGeometries, LefMacro, LefMacroClass, LefMacroFunction, LefMacroPin, LefMacroSource, LefNetExpr, LefPinAntennaModel, LefPinDirection, LefPinShape, LefPort, LefPortClass, LefPropertyObjectType, LefSignalUse, LefSite, LefSpacingOrDesignRuleWidth, LefViaInstance};
+use super::{parse_number, LefParser, LefReadResult};
+use super::lexer::TokenKind;
+use super::site::parse_orient;
+use std::str::FromStr;
+
+impl LefParser {
+    pub(super) fn parse_macro(&mut self) -> LefReadResult<()> {
+        let mut makcro = LefMacro { name: self.name()?, ..Default::default() };
+
+        // `SITE name` is listed before the site patterns, the last name wins
+        let mut site_name = None;
+        let mut site_patterns = vec![];
+
+        while !self.at("END") {
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "CLASS" => {
+                    let class = self.words_until_semicolon()?.to_ascii_uppercase();
+                    makcro.class = Some(LefMacroClass::from_str(&class).map_err(|msg| self.error(msg))?);
+                }
+                "FIXEDMASK" => {
+                    self.end_statement()?;
+                    if self.version >= 5.8 {
+                        makcro.fixed_mask = true;
+                    }
+                }
+                "FOREIGN" => {
+                    let mut foreign = LefForeign { cell_name: self.name()?, ..Default::default() };
+                    if self.at_number() || self.at("(") {
+                        foreign.point = Some(self.point()?);
+                    }
+                    if !self.at(";") {
+                        let orient = self.name()?;
+                        foreign.orient = Some(parse_orient(&orient)
+                            .ok_or_else(|| self.error(format!("invalid FOREIGN orientation '{}'", orient)))?);
+                    }
+                    self.end_statement()?;
+                    makcro.foreign.push(foreign);
+                }
+                "ORIGIN" => {
+                    makcro.origin = self.point()?;
+                    self.end_statement()?;
+                }
+                "EEQ" => makcro.eeq = Some(self.name_statement()?),
+                "LEQ" => {
+                    let leq = self.name_statement()?;
+                    if self.version < 5.6 {
+                        makcro.leq = Some(leq);
+                    }
+                }
+                "SOURCE" => {
+                    let source = self.keyword()?;
+                    self.end_statement()?;
+                    if self.version < 5.6 {
+                        makcro.source = Some(LefMacroSource::from_str(&source)
+                            .map_err(|_| self.error(format!("unknown macro source '{}'", source)))?);
+                    }
+                }
+                "POWER" => {
+                    let power = self.number()?;
+                    self.end_statement()?;
+                    if self.version < 5.4 {
+                        makcro.power = Some(power);
+                    }
+                }
+                "GENERATOR" => makcro.generator = Some(self.name_statement()?),
+                "GENERATE" => {
+                    makcro.generate = Some((self.name()?, self.name()?));
+                    self.end_statement()?;
+                }
+                "FUNCTION" => {
+                    let function = self.keyword()?;
+                    makcro.function = Some(match function.as_str() {
+                        "BUFFER" => LefMacroFunction::BUFFER,
+                        "INVERTER" => LefMacroFunction::INVERTER,
+                        _ => return Err(self.error(format!("unknown macro function '{}'", function))),
+                    });
+                    self.end_statement()?;
+                }
+                "CLOCKTYPE" => makcro.clock_type = Some(self.name_statement()?),
+                "SIZE" => {
+                    let width = self.number()?;
+                    self.expect("BY")?;
+                    makcro.size = Some((width, self.number()?));
+                    self.end_statement()?;
+                }
+                "SYMMETRY" => makcro.symmetry = self.parse_symmetry()?,
+                "SITE" => {
+                    // A site pattern has an origin after the site name
+                    let is_pattern = self.tokens.get(self.pos + 1)
+                        .is_some_and(|token| token.kind == TokenKind::Word && parse_number(&token.text).is_some());
+                    if is_pattern {
+                        site_patterns.push(self.parse_site_pattern()?);
+                        self.end_statement()?;
+                    } else {
+                        site_name = Some(self.name_statement()?);
+                    }
+                }
+                "PIN" => {
+                    let pin = self.parse_pin()?;
+                    makcro.pins.push(pin);
+                }
+                "OBS" => {
+                    (makcro.obs, makcro.obs_vias) = self.parse_geometries()?;
+                    self.expect("END")?;
+                }
+                "DENSITY" => {
+                    let density = self.parse_density()?;
+                    makcro.density.extend(density);
+                }
+                "TIMING" => self.parse_timing(&mut makcro.timings)?,
+                "PROPERTY" => self.parse_properties(LefPropertyObjectType::Macro, &mut makcro.properties)?,
+                _ => return Err(self.error(format!("unknown MACRO statement '{}'", keyword))),
+            }
+        }
+        self.expect_end(&makcro.name)?;
+
+        if let Some(name) = site_name {
+            makcro.sites.push(LefSite { name, ..Default::default() });
+        }
+        makcro.sites.extend(site_patterns);
+
+        self.macros.insert(makcro.name.clone(), makcro);
+        Ok(())
+    }
+
+    fn parse_pin(&mut self) -> LefReadResult<LefMacroPin> {
+        let mut pin = LefMacroPin { name: self.name()?, ..Default::default() };
+        let mut antenna_model = None;
+
+        while !self.at("END") {
+            let keyword = self.keyword()?;
+            let electrical = &mut pin.electrical;
+            match keyword.as_str() {
+                "FOREIGN" | "LEQ" | "SCANUSE" | "ANTENNASIZE" | "ANTENNAMETALAREA" | "ANTENNAMETALLENGTH" => self.skip_statement()?,
+                "DIRECTION" => {
+                    let direction = self.words_until_semicolon()?.to_ascii_uppercase();
+                    pin.direction = Some(LefPinDirection::from_str(&direction)
+                        .map_err(|_| self.error(format!("unknown pin direction '{}'", direction)))?);
+                }
+                "USE" => {
+                    let signal_use = self.keyword()?;
+                    self.end_statement()?;
+                    pin.signal_use = Some(LefSignalUse::from_str(&signal_use)
+                        .map_err(|_| self.error(format!("unknown pin use '{}'", signal_use)))?);
+                }
+                "SHAPE" => {
+                    if !self.accept(";") {
+                        let shape = self.keyword()?;
+                        self.end_statement()?;
+                        pin.shape_type = Some(LefPinShape::from_str(&shape)
+                            .map_err(|_| self.error(format!("unknown pin shape '{}'", shape)))?);
+                    }
+                }
+                "MUSTJOIN" => pin.must_join = Some(self.name_statement()?),
+                "TAPERRULE" => pin.taper_rule = Some(self.name_statement()?),
+                "NETEXPR" => {
+                    let net_expr = self.name_statement()?;
+                    if self.version >= 5.6 {
+                        pin.net_expr = Some(LefNetExpr::from_str(&net_expr)
+                            .map_err(|_| self.error(format!("invalid NETEXPR '{}'", net_expr)))?);
+                    }
+                }
+                "SUPPLYSENSITIVITY" | "GROUNDSENSITIVITY" => {
+                    let name = self.name_statement()?;
+                    if self.version >= 5.6 {
+                        match keyword.as_str() {
+                            "SUPPLYSENSITIVITY" => pin.supply_sensitivity = Some(name),
+                            _ => pin.ground_sensitivity = Some(name),
+                        }
+                    }
+                }
+
+                // Electrical data, most of it is obsolete since LEF 5.4
+                "MAXDELAY" => electrical.max_delay = Some(self.number_statement()?),
+                "MAXLOAD" => electrical.max_load = Some(self.number_statement()?),
+                "RISESLEWLIMIT" => electrical.rise_slew_limit = Some(self.number_statement()?),
+                "FALLSLEWLIMIT" => electrical.fall_slew_limit = Some(self.number_statement()?),
+                "POWER" | "LEAKAGE" | "RISETHRESH" | "FALLTHRESH" | "RISESATCUR" | "FALLSATCUR" | "VLO" | "VHI" | "TIEOFFR"
+                | "CAPACITANCE" | "RESISTANCE" | "PULLDOWNRES" | "RISEVOLTAGETHRESHOLD" | "FALLVOLTAGETHRESHOLD" => {
+                    let value = self.number_statement()?;
+                    if self.version < 5.4 {
+                        let field = match keyword.as_str() {
+                            "POWER" => &mut electrical.power,
+                            "LEAKAGE" => &mut electrical.leakage,
+                            "RISETHRESH" => &mut electrical.rise_threshold,
+                            "FALLTHRESH" => &mut electrical.fall_threshold,
+                            "RISESATCUR" => &mut electrical.rise_sat_cur,
+                            "FALLSATCUR" => &mut electrical.fall_sat_cur,
+                            "VLO" => &mut electrical.v_lo,
+                            "VHI" => &mut electrical.v_hi,
+                            "TIEOFFR" => &mut electrical.tieoff_r,
+                            "CAPACITANCE" => &mut electrical.capacitance,
+                            "RESISTANCE" => &mut electrical.resistance,
+                            "PULLDOWNRES" => &mut electrical.pulldown_res,
+                            "RISEVOLTAGETHRESHOLD" => &mut electrical.rise_voltage_threshold,
+                            _ => &mut electrical.fall_voltage_threshold,
+                        };
+                        *field = Some(value);
+                    }
+                }
+                "OUTPUTNOISEMARGIN" | "OUTPUTRESISTANCE" | "INPUTNOISEMARGIN" => {
+                    let (high, low) = (self.number()?, self.number()?);
+                    self.end_statement()?;
+                    if self.version < 5.4 {
+                        let field = match keyword.as_str() {
+                            "OUTPUTNOISEMARGIN" => &mut electrical.output_noise_margin,
+                            "OUTPUTRESISTANCE" => &mut electrical.output_resistance,
+                            _ => &mut electrical.input_noise_margin,
+                        };
+                        *field = Some((high, low));
+                    }
+                }
+                "CURRENTSOURCE" => {
+                    let current_source = self.keyword()?;
+                    self.end_statement()?;
+                    if self.version < 5.4 {
+                        electrical.current_source = Some(current_source);
+                    }
+                }
+                "IV_TABLES" => {
+                    let (high, low) = (self.name()?, self.name()?);
+                    self.end_statement()?;
+                    if self.version < 5.4 {
+                        electrical.iv_tables = Some((low, high));
+                    }
+                }
+
+                // Antenna data, gate area and ratios belong to the current ANTENNAMODEL
+                "ANTENNAPARTIALMETALAREA" => pin.antenna.partial_metal_area.push(self.antenna_value()?),
+                "ANTENNAPARTIALMETALSIDEAREA" => pin.antenna.partial_metal_side_area.push(self.antenna_value()?),
+                "ANTENNAPARTIALCUTAREA" => pin.antenna.partial_cut_area.push(self.antenna_value()?),
+                "ANTENNADIFFAREA" => pin.antenna.diff_area.push(self.antenna_value()?),
+                "ANTENNAMODEL" => {
+                    let oxide = self.keyword()?;
+                    self.end_statement()?;
+                    let index = oxide.strip_prefix("OXIDE")
+                        .and_then(|number| number.parse::<usize>().ok())
+                        .filter(|number| (1..=32).contains(number))
+                        .ok_or_else(|| self.error(format!("unknown ANTENNAMODEL '{}'", oxide)))?;
+                    let models = &mut pin.antenna.models;
+                    while models.len() < index {
+                        models.push(LefPinAntennaModel { oxide: format!("OXIDE{}", models.len() + 1), ..Default::default() });
+                    }
+                    models[index - 1] = LefPinAntennaModel { oxide, ..Default::default() };
+                    antenna_model = Some(index - 1);
+                }
+                "ANTENNAGATEAREA" | "ANTENNAMAXAREACAR" | "ANTENNAMAXSIDEAREACAR" | "ANTENNAMAXCUTCAR" => {
+                    let value = self.antenna_value()?;
+                    let models = &mut pin.antenna.models;
+                    if models.is_empty() {
+                        models.push(LefPinAntennaModel { oxide: "OXIDE1".into(), ..Default::default() });
+                    }
+                    let model = &mut models[antenna_model.unwrap_or(0)];
+                    match keyword.as_str() {
+                        "ANTENNAGATEAREA" => model.gate_area.push(value),
+                        "ANTENNAMAXAREACAR" => model.max_area_car.push(value),
+                        "ANTENNAMAXSIDEAREACAR" => model.max_side_area_car.push(value),
+                        _ => model.max_cut_car.push(value),
+                    }
+                }
+
+                "PROPERTY" => self.parse_properties(LefPropertyObjectType::Pin, &mut pin.properties)?,
+                "PORT" => {
+                    let port = self.parse_port()?;
+                    pin.ports.push(port);
+                }
+                _ => return Err(self.error(format!("unknown PIN statement '{}'", keyword))),
+            }
+        }
+        self.expect_end(&pin.name)?;
+
+        Ok(pin)
+    }
+
+    /// Read `value [LAYER name] ;` of a pin antenna statement.
+    fn antenna_value(&mut self) -> LefReadResult<LefAntennaValue> {
+        let value = self.number()?;
+        let layer = if self.accept("LAYER") { Some(self.name()?) } else { None };
+        self.end_statement()?;
+        Ok(LefAntennaValue { value, layer })
+    }
+
+    fn parse_port(&mut self) -> LefReadResult<LefPort> {
+        let mut port = LefPort::default();
+        if self.accept("CLASS") {
+            let class = self.keyword()?;
+            self.end_statement()?;
+            port.class = Some(LefPortClass::from_str(&class)
+                .map_err(|_| self.error(format!("unknown port class '{}'", class)))?);
+        }
+        (port.geometries, port.vias) = self.parse_geometries()?;
+        self.expect("END")?;
+        Ok(port)
+    }
+
+    /// Read the LAYER blocks and the VIA placements of a PORT or OBS up to its END.
+    fn parse_geometries(&mut self) -> LefReadResult<(Vec<LefLayerGeometries>, Vec<LefViaInstance>)> {
+        let mut all_geometries: Vec<LefLayerGeometries> = vec![];
+        let mut vias = vec![];
+
+        while !self.at("END") {
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "LAYER" => {
+                    let mut geometries = LefLayerGeometries { layer_name: self.name()?, ..Default::default() };
+                    if self.accept("EXCEPTPGNET") {
+                        geometries.except_pg_net = self.version >= 5.7;
+                    }
+                    if self.accept("SPACING") {
+                        geometries.spacing_or_designrule_width = Some(LefSpacingOrDesignRuleWidth::MinSpacing(self.number()?));
+                    } else if self.accept("DESIGNRULEWIDTH") {
+                        geometries.spacing_or_designrule_width = Some(LefSpacingOrDesignRuleWidth::DesignRuleWidth(self.number()?));
+                    }
+                    self.end_statement()?;
+                    all_geometries.push(geometries);
+                }
+                "WIDTH" | "PATH" | "RECT" | "POLYGON" if all_geometries.is_empty() => {
+                    return Err(self.error("geometry defined before any LAYER"));
+                }
+                "WIDTH" => {
+                    let width = self.number_statement()?;
+                    if let Some(geometries) = all_geometries.last_mut() {
+                        geometries.width = Some(width);
+                    }
+                }
+                "PATH" | "RECT" | "POLYGON" => {
+                    let mask = self.number_if("MASK")?;
+                    let iterate = self.accept("ITERATE");
+                    let mut points = vec![];
+                    while self.at_number() || self.at("(") {
+                        points.push(self.point()?);
+                    }
+                    let step_pattern = if iterate { self.parse_step_pattern()? } else { None };
+                    self.end_statement()?;
+
+                    let Some(geometries) = all_geometries.last_mut() else {
+                        continue;
+                    };
+                    // Masks are a LEF 5.8 feature, older files drop the shape
+                    if self.version < 5.8 && mask > 0 {
+                        continue;
+                    }
+                    let mask = mask_number(mask);
+                    let geometry = match keyword.as_str() {
+                        "PATH" => LefGeometry::Path(geometries.width, points, mask),
+                        "RECT" => {
+                            let [p1, p2] = points[..] else {
+                                return Err(self.error("RECT requires two points"));
+                            };
+                            LefGeometry::Rect(p1, p2, mask)
+                        }
+                        _ => LefGeometry::Polygon(points, mask),
+                    };
+                    geometries.geometries.push(match step_pattern {
+                        Some(pattern) => LefGeometry::Iterate(Box::new(geometry), pattern),
+                        None => geometry,
+                    });
+                }
+                "VIA" => {
+                    let iterate = self.accept("ITERATE");
+                    let mask = self.number_if("MASK")?;
+                    let location = self.point()?;
+                    let via_name = self.name()?;
+                    let step_pattern = if iterate { self.parse_step_pattern()? } else { None };
+                    self.end_statement()?;
+
+                    if self.version < 5.8 && mask > 0 {
+                        continue;
+                    }
+                    let masks = (mask_number(mask / 100), mask_number(mask / 10 % 10), mask_number(mask % 10));
+                    vias.push(LefViaInstance { via_name, location, masks, step_pattern });
+                }
+                _ => return Err(self.error(format!("unknown geometry statement '{}'", keyword))),
+            }
+        }
+
+        Ok((all_geometries, vias))
+    }
+
+    fn parse_density(&mut self) -> LefReadResult<Vec<LefDensityLayer>> {
+        let mut density = vec![];
+        while !self.accept("END") {
+            self.expect("LAYER")?;
+            let mut layer = LefDensityLayer { layer_name: self.name_statement()?, ..Default::default() };
+            while self.accept("RECT") {
+                let rect = (self.point()?, self.point()?);
+                let value = self.number_statement()?;
+                layer.rects.push(LefDensityRect { rect, density: value });
+            }
+            density.push(layer);
+        }
+        Ok(density)
+    }
+
+    /// Read `name ;`.
+    pub(super) fn name_statement(&mut self) -> LefReadResult<String> {
+        let name = self.name()?;
+        self.end_statement()?;
+        Ok(name)
+    }
+
+    /// Read the words of a statement up to its `;`, separated by spaces.
+    fn words_until_semicolon(&mut self) -> LefReadResult<String> {
+        let mut words = vec![];
+        while !self.accept(";") {
+            words.push(self.name()?);
+        }
+        Ok(words.join(" "))
+    }
+
+    /// Read the number after an optional keyword, 0 without the keyword.
+    fn number_if(&mut self, keyword: &str) -> LefReadResult<u32> {
+        Ok(if self.accept(keyword) { self.number()? as u32 } else { 0 })
+    }
+}
+
+/// A mask number of 0 means no mask.
+fn mask_number(mask: u32) -> Option<u32> {
+    if mask > 0 { Some(mask) } else { None }
+}
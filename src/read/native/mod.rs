@@ -0,0 +1,670 @@
+mod lexer;
+mod layer;
+mod via;
+mod site;
+mod makro;
+mod property;
+mod nondefault;
+mod electrical;
+mod timing;
+mod array;
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::path::Path;
+use std::str::FromStr;
+use super::{LefReadError, LefReadResult};
+use lexer::{Token, TokenKind};
+use crate::LefCellLibrary;
+use crate::LefClearanceMeasure;
+use crate::LefExtension;
+use crate::LefMacro;
+use crate::LefMaxViaStack;
+use crate::LefTechnology;
+
+impl LefTechnology {
+    /// Load a technology LEF file with the pure-Rust parser.
+    pub fn load_file_native<P: AsRef<Path>>(path: P) -> LefReadResult<Self> {
+        let parser = LefParser::parse_file(path.as_ref(), true)?;
+        Ok(parser.tech)
+    }
+}
+
+impl LefCellLibrary {
+    /// Load a cell library LEF file with the pure-Rust parser.
+    pub fn load_file_native<P: AsRef<Path>>(path: P) -> LefReadResult<Self> {
+        let parser = LefParser::parse_file(path.as_ref(), false)?;
+        let tech = parser.tech;
+        Ok(LefCellLibrary {
+            version: tech.version,
+            busbitchars: tech.busbitchars,
+            dividerchar: tech.dividerchar,
+            vias: tech.vias,
+            sites: tech.sites,
+            macros: parser.macros,
+            property_definitions: tech.property_definitions,
+            extensions: tech.extensions,
+        })
+    }
+}
+
+/// Version assumed by Si2 if the file has no VERSION statement.
+const DEFAULT_VERSION: f64 = 5.8;
+
+/// Recursive descent LEF parser. Statements that Si2 drops for the file VERSION are dropped here too.
+pub struct LefParser {
+    tokens: Vec<Token>,
+    pos: usize,
+    /// Line of the last consumed token.
+    line: usize,
+    version: f64,
+    /// Reading a technology LEF, a cell library skips the checks against technology definitions.
+    is_technology: bool,
+    tech: LefTechnology,
+    macros: HashMap<String, LefMacro>,
+}
+
+//=====================================================================
+//                    Token Stream
+//=====================================================================
+
+impl LefParser {
+    fn parse_file(path: &Path, is_technology: bool) -> LefReadResult<Self> {
+        let input = std::fs::read_to_string(path)?;
+        Self::parse_str(&input, is_technology)
+    }
+
+    fn parse_str(input: &str, is_technology: bool) -> LefReadResult<Self> {
+        let mut parser = Self {
+            tokens: lexer::tokenize(input)?,
+            pos: 0,
+            line: 1,
+            version: DEFAULT_VERSION,
+            is_technology,
+            tech: Default::default(),
+            macros: HashMap::new(),
+        };
+        parser.parse_library()?;
+        Ok(parser)
+    }
+
+    fn error(&self, msg: impl Display) -> LefReadError {
+        LefReadError::Msg(format!("line {}: {}", self.line, msg))
+    }
+
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    /// Tell whether the next token is the keyword.
+    fn at(&self, keyword: &str) -> bool {
+        self.peek().is_some_and(|token| token.kind == TokenKind::Word && token.text.eq_ignore_ascii_case(keyword))
+    }
+
+    /// Tell whether the next token is a number.
+    fn at_number(&self) -> bool {
+        self.peek().is_some_and(|token| token.kind == TokenKind::Word && parse_number(&token.text).is_some())
+    }
+
+    /// Consume the next token if it is the keyword.
+    fn accept(&mut self, keyword: &str) -> bool {
+        let found = self.at(keyword);
+        if found {
+            self.pos += 1;
+            self.line = self.tokens[self.pos - 1].line;
+        }
+        found
+    }
+
+    fn expect(&mut self, keyword: &str) -> LefReadResult<()> {
+        if self.accept(keyword) {
+            return Ok(());
+        }
+        match self.peek() {
+            Some(token) => {
+                let msg = format!("expected '{}', found '{}'", keyword, token.text);
+                self.line = token.line;
+                Err(self.error(msg))
+            }
+            None => Err(self.error(format!("expected '{}', found end of file", keyword))),
+        }
+    }
+
+    fn next_token(&mut self) -> LefReadResult<Token> {
+        let Some(token) = self.tokens.get(self.pos).cloned() else {
+            return Err(self.error("unexpected end of file"));
+        };
+        self.pos += 1;
+        self.line = token.line;
+        Ok(token)
+    }
+
+    /// Read a name or a quoted string.
+    fn name(&mut self) -> LefReadResult<String> {
+        let token = self.next_token()?;
+        match token.kind {
+            TokenKind::Extension => Err(self.error("unexpected BEGINEXT")),
+            _ => Ok(token.text),
+        }
+    }
+
+    /// Read a keyword, upper-cased.
+    fn keyword(&mut self) -> LefReadResult<String> {
+        Ok(self.name()?.to_ascii_uppercase())
+    }
+
+    fn number(&mut self) -> LefReadResult<f64> {
+        let token = self.next_token()?;
+        match token.kind {
+            TokenKind::Word => parse_number(&token.text),
+            _ => None,
+        }
+        .ok_or_else(|| self.error(format!("expected a number, found '{}'", token.text)))
+    }
+
+    /// Read a point, optionally in parentheses.
+    fn point(&mut self) -> LefReadResult<(f64, f64)> {
+        let parenthesized = self.accept("(");
+        let point = (self.number()?, self.number()?);
+        if parenthesized {
+            self.expect(")")?;
+        }
+        Ok(point)
+    }
+
+    fn points_until_semicolon(&mut self) -> LefReadResult<Vec<(f64, f64)>> {
+        let mut points = vec![];
+        while !self.accept(";") {
+            points.push(self.point()?);
+        }
+        Ok(points)
+    }
+
+    fn numbers_until_semicolon(&mut self) -> LefReadResult<Vec<f64>> {
+        let mut numbers = vec![];
+        while !self.accept(";") {
+            numbers.push(self.number()?);
+        }
+        Ok(numbers)
+    }
+
+    fn end_statement(&mut self) -> LefReadResult<()> {
+        self.expect(";")
+    }
+
+    /// Skip the rest of a statement including the `;`.
+    fn skip_statement(&mut self) -> LefReadResult<()> {
+        while !self.accept(";") {
+            self.next_token()?;
+        }
+        Ok(())
+    }
+
+    /// Read `END name` of a named block.
+    fn expect_end(&mut self, name: &str) -> LefReadResult<()> {
+        self.expect("END")?;
+        let end_name = self.name()?;
+        if end_name != name {
+            return Err(self.error(format!("END {} does not match '{}'", end_name, name)));
+        }
+        Ok(())
+    }
+
+    /// Read `ON` or `OFF`.
+    fn on_off(&mut self) -> LefReadResult<bool> {
+        match self.keyword()?.as_str() {
+            "ON" => Ok(true),
+            "OFF" => Ok(false),
+            other => Err(self.error(format!("expected ON or OFF, found '{}'", other))),
+        }
+    }
+}
+
+/// Parse a token as number like the Si2 lexer: it must start with a digit, `.` or a sign.
+fn parse_number(text: &str) -> Option<f64> {
+    let first = text.chars().next()?;
+    let numeric = first.is_ascii_digit() || (matches!(first, '.' | '-' | '+') && text.len() > 1);
+    if numeric { text.parse().ok() } else { None }
+}
+
+/// Format a number like `printf("%.11g")`, which is how Si2 passes numerical property values as text.
+fn format_number(value: f64) -> String {
+    const PRECISION: i32 = 11;
+    if value == 0.0 || !value.is_finite() {
+        return format!("{}", value);
+    }
+
+    let scientific = format!("{:.*e}", (PRECISION - 1) as usize, value);
+    let (mantissa, exponent) = scientific.split_once('e').unwrap();
+    let exponent: i32 = exponent.parse().unwrap();
+    let trim = |text: &str| -> String {
+        if text.contains('.') {
+            text.trim_end_matches('0').trim_end_matches('.').to_string()
+        } else {
+            text.to_string()
+        }
+    };
+
+    if !(-4..PRECISION).contains(&exponent) {
+        let sign = if exponent < 0 { '-' } else { '+' };
+        format!("{}e{}{:02}", trim(mantissa), sign, exponent.abs())
+    } else {
+        trim(&format!("{:.*}", (PRECISION - 1 - exponent) as usize, value))
+    }
+}
+
+/// Convert a VERSION string like Si2: `major.minor[.subminor]`.
+fn parse_version(text: &str) -> Option<f64> {
+    let mut parts = text.split('.');
+    let major: f64 = parts.next()?.parse().ok()?;
+    let minor: f64 = parts.next().map_or(Some(0.0), |part| part.parse().ok())?;
+    let subminor: f64 = parts.next().map_or(Some(0.0), |part| part.parse().ok())?;
+    format!("{:.4}", major + minor / 10.0 + subminor / 1000.0).parse().ok()
+}
+
+//=====================================================================
+//                    Library Statements
+//=====================================================================
+
+impl LefParser {
+    fn parse_library(&mut self) -> LefReadResult<()> {
+        while let Some(token) = self.peek() {
+            if token.kind == TokenKind::Extension {
+                let token = self.next_token()?;
+                let extension = LefExtension::from_str(&token.text)
+                    .map_err(|_| LefReadError::Msg(format!("invalid BEGINEXT block '{}'", token.text.trim())))?;
+                self.tech.extensions.push(extension);
+                continue;
+            }
+
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "VERSION" => self.parse_version()?,
+                "BUSBITCHARS" => {
+                    let chars = self.name()?;
+                    self.end_statement()?;
+                    let mut chars = chars.chars();
+                    self.tech.busbitchars = (chars.next().unwrap_or('['), chars.next().unwrap_or(']'));
+                }
+                "DIVIDERCHAR" => {
+                    let chars = self.name()?;
+                    self.end_statement()?;
+                    self.tech.dividerchar = chars.chars().next().unwrap_or('/');
+                }
+                "NAMESCASESENSITIVE" => self.skip_statement()?,
+                "UNITS" => self.parse_units()?,
+                "MANUFACTURINGGRID" => {
+                    self.tech.manufacturing_grid = Some(self.number()?);
+                    self.end_statement()?;
+                }
+                "CLEARANCEMEASURE" => {
+                    let measure = self.keyword()?;
+                    self.tech.clearance_measure = LefClearanceMeasure::from_str(&measure)
+                        .map_err(|_| self.error(format!("unknown clearance measure '{}'", measure)))?;
+                    self.end_statement()?;
+                }
+                "USEMINSPACING" => {
+                    let object = self.keyword()?;
+                    let value = self.on_off()?;
+                    self.end_statement()?;
+                    // USEMINSPACING PIN is obsolete, only OBS is kept
+                    if object == "OBS" {
+                        self.tech.use_min_spacing_obs = Some(value);
+                    }
+                }
+                "MAXVIASTACK" => {
+                    let max_stack = self.number()? as u32;
+                    let range = if self.accept("RANGE") {
+                        Some((self.name()?, self.name()?))
+                    } else {
+                        None
+                    };
+                    self.end_statement()?;
+                    self.tech.max_via_stack = Some(LefMaxViaStack { max_stack, range });
+                }
+                "NOWIREEXTENSIONATPIN" => {
+                    let value = self.on_off()?;
+                    self.end_statement()?;
+                    if self.version < 5.6 {
+                        self.tech.no_wire_extension_at_pin = Some(value);
+                    }
+                }
+                "MINFEATURE" => {
+                    let min_feature = (self.number()?, self.number()?);
+                    self.end_statement()?;
+                    if self.version < 5.4 {
+                        self.tech.min_feature = Some(min_feature);
+                    }
+                }
+                "FIXEDMASK" => {
+                    self.end_statement()?;
+                    if self.version >= 5.8 {
+                        self.tech.fixed_mask = true;
+                    }
+                }
+                "INPUTPINANTENNASIZE" | "OUTPUTPINANTENNASIZE" | "INOUTPINANTENNASIZE"
+                | "ANTENNAINPUTGATEAREA" | "ANTENNAINOUTDIFFAREA" | "ANTENNAOUTPUTDIFFAREA" => self.skip_statement()?,
+                "SPACING" => {
+                    while !(self.accept("END") && self.accept("SPACING")) {
+                        self.next_token()?;
+                    }
+                }
+                "IRDROP" => self.parse_ir_drop()?,
+                "DIELECTRIC" => {
+                    let dielectric = self.number()?;
+                    self.end_statement()?;
+                    if self.version < 5.4 {
+                        self.tech.dielectric = Some(dielectric);
+                    }
+                }
+                "UNIVERSALNOISEMARGIN" => self.parse_noise_margin()?,
+                "EDGERATETHRESHOLD1" | "EDGERATETHRESHOLD2" | "EDGERATESCALEFACTOR" => self.parse_edge_rate(&keyword)?,
+                "NOISETABLE" | "CORRECTIONTABLE" => self.parse_cross_talk_table(&keyword)?,
+                "PROPERTYDEFINITIONS" => self.parse_property_definitions()?,
+                "LAYER" => self.parse_layer()?,
+                "VIA" => {
+                    let (name, via) = self.parse_via()?;
+                    self.tech.vias.insert(name, via);
+                }
+                "VIARULE" => self.parse_via_rule()?,
+                "NONDEFAULTRULE" => self.parse_non_default_rule()?,
+                "SITE" => self.parse_site()?,
+                "MACRO" => self.parse_macro()?,
+                "ARRAY" => self.parse_array()?,
+                "END" => {
+                    self.expect("LIBRARY")?;
+                    break;
+                }
+                _ => return Err(self.error(format!("unknown statement '{}'", keyword))),
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_version(&mut self) -> LefReadResult<()> {
+        let text = self.name()?;
+        self.end_statement()?;
+        let version = parse_version(&text).ok_or_else(|| self.error(format!("invalid VERSION '{}'", text)))?;
+        if version > DEFAULT_VERSION {
+            return Err(self.error(format!("VERSION {} is not supported", text)));
+        }
+        self.version = version;
+        self.tech.version = Some(version);
+        Ok(())
+    }
+
+    fn parse_units(&mut self) -> LefReadResult<()> {
+        while !self.accept("END") {
+            let keyword = self.keyword()?;
+            let unit = match keyword.as_str() {
+                "TIME" => "NANOSECONDS",
+                "CAPACITANCE" => "PICOFARADS",
+                "RESISTANCE" => "OHMS",
+                "POWER" => "MILLIWATTS",
+                "CURRENT" => "MILLIAMPS",
+                "VOLTAGE" => "VOLTS",
+                "DATABASE" => "MICRONS",
+                "FREQUENCY" => "MEGAHERTZ",
+                _ => return Err(self.error(format!("unknown UNITS statement '{}'", keyword))),
+            };
+            self.expect(unit)?;
+            let value = self.number()?;
+            self.end_statement()?;
+
+            let units = &mut self.tech.units;
+            match unit {
+                "NANOSECONDS" => units.time_ns = value,
+                "PICOFARADS" => units.capacitance_pf = value,
+                "OHMS" => units.resistance_ohms = value,
+                "MILLIWATTS" => units.power_mw = value,
+                "MILLIAMPS" => units.current_ma = value,
+                "VOLTS" => units.voltage_v = value,
+                "MICRONS" => units.database_microns = value,
+                _ => units.frequency_mega_hz = value,
+            }
+        }
+        self.expect("UNITS")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LefGeometry, LefLayer, LefMacroClass, LefOrient, LefPinDirection, LefPortClass, LefPropertyValue, LefRoutingDirection, LefViaShape};
+
+    fn parse(input: &str) -> LefParser {
+        LefParser::parse_str(input, true).unwrap()
+    }
+
+    fn bench(name: &str) -> String {
+        format!("{}/bench/NangateOpenCellLibrary.{}.lef", env!("CARGO_MANIFEST_DIR"), name)
+    }
+
+    #[test]
+    fn formats_numbers_like_printf() {
+        assert_eq!(format_number(0.5), "0.5");
+        assert_eq!(format_number(2000.0), "2000");
+        assert_eq!(format_number(7.7161e-5), "7.7161e-05");
+        assert_eq!(format_number(1e12), "1e+12");
+        assert_eq!(parse_version("5.8"), Some(5.8));
+        assert_eq!(parse_version("5.5.1"), Some(5.501));
+        assert_eq!(parse_number("metal1"), None);
+    }
+
+    #[test]
+    fn reads_header_statements() {
+        let parser = parse(r#"
+            VERSION 5.7 ;
+            BUSBITCHARS "<>" ;
+            DIVIDERCHAR ":" ;
+            UNITS
+              DATABASE MICRONS 2000 ;
+              RESISTANCE OHMS 1000 ;
+            END UNITS
+            MANUFACTURINGGRID 0.005 ;
+            MINFEATURE 0.1 0.1 ;
+            END LIBRARY
+        "#);
+        let tech = parser.tech;
+        assert_eq!(tech.version, Some(5.7));
+        assert_eq!(tech.busbitchars, ('<', '>'));
+        assert_eq!(tech.dividerchar, ':');
+        assert_eq!(tech.units.database_microns, 2000.0);
+        assert_eq!(tech.units.resistance_ohms, 1000.0);
+        assert_eq!(tech.manufacturing_grid, Some(0.005));
+        // MINFEATURE is obsolete since LEF 5.4
+        assert_eq!(tech.min_feature, None);
+    }
+
+    #[test]
+    fn checks_row_pattern_in_technology_only() {
+        let input = "
+            SITE core SIZE 0.2 BY 1.4 ; END core
+            SITE double ROWPATTERN core N other FS ; END double
+        ";
+        let Err(err) = LefParser::parse_str(input, true) else {
+            panic!("undefined ROWPATTERN site accepted");
+        };
+        assert!(err.to_string().contains("undefined site 'other'"));
+
+        let parser = LefParser::parse_str(input, false).unwrap();
+        let row_pattern = &parser.tech.sites["double"].row_pattern;
+        assert_eq!(row_pattern[1], ("other".to_string(), LefOrient::FS));
+    }
+
+    #[test]
+    fn reads_routing_layer() {
+        let parser = parse("
+            LAYER metal1
+              TYPE ROUTING ;
+              DIRECTION HORIZONTAL ;
+              PITCH 0.14 ;
+              WIDTH 0.07 ;
+              SPACING 0.065 ;
+              SPACING 0.1 RANGE 0.2 10 ;
+              RESISTANCE RPERSQ 0.38 ;
+            END metal1
+            LAYER poly
+              TYPE MASTERSLICE ;
+            END poly
+        ");
+        let [LefLayer::Routing(layer)] = &parser.tech.layers[..] else {
+            panic!("expected one routing layer, got {:?}", parser.tech.layers);
+        };
+        assert_eq!(layer.direction, LefRoutingDirection::Horizontal);
+        assert_eq!(layer.pitch, (0.14, 0.14));
+        assert_eq!(layer.width, 0.07);
+        assert_eq!(layer.spacing.len(), 2);
+        assert_eq!(layer.spacing[0].min_spacing, 0.065);
+        assert!(layer.spacing[1].spacing_type.is_some());
+        assert_eq!(layer.resistance, Some(0.38));
+    }
+
+    #[test]
+    fn reads_via_shapes_in_order() {
+        let parser = parse("
+            VIA via1 DEFAULT
+              LAYER metal1 ;
+                POLYGON 0 0 1 0 1 1 ;
+                RECT -0.1 -0.1 0.1 0.1 ;
+              LAYER via1 ;
+                RECT MASK 2 ( -0.05 -0.05 ) ( 0.05 0.05 ) ;
+            END via1
+        ");
+        let via = &parser.tech.vias["via1"];
+        assert!(via.is_default);
+        let names: Vec<_> = via.geometry.iter().map(|layer| layer.name.as_str()).collect();
+        assert_eq!(names, ["metal1", "via1"]);
+        assert!(matches!(via.geometry[0].shapes[0], LefViaShape::Rect(..)));
+        assert!(matches!(via.geometry[0].shapes[1], LefViaShape::Polygon(..)));
+        assert_eq!(via.geometry[1].shapes[0], LefViaShape::Rect((-0.05, -0.05), (0.05, 0.05), Some(2)));
+    }
+
+    #[test]
+    fn reads_macro() {
+        let parser = parse("
+            MACRO INV
+              CLASS CORE ;
+              FOREIGN INV 0 0 ;
+              SIZE 0.38 BY 1.4 ;
+              SYMMETRY X Y ;
+              SITE core ;
+              PIN Z
+                DIRECTION OUTPUT TRISTATE ;
+                USE SIGNAL ;
+                ANTENNADIFFAREA 0.1 LAYER metal1 ;
+                ANTENNAMODEL OXIDE2 ;
+                ANTENNAGATEAREA 0.2 ;
+                PORT
+                  CLASS NONE ;
+                  LAYER metal1 ;
+                    RECT 0 0 0.1 0.5 ;
+                  VIA 0.05 0.05 via1 ;
+                END
+              END Z
+              OBS
+                VIA ITERATE 0 0 via1 DO 2 BY 1 STEP 0.2 0 ;
+                LAYER metal1 ;
+                  WIDTH 0.07 ;
+                  PATH 0 0 0 1 ;
+                  RECT ITERATE 0 0 0.1 0.1 DO 2 BY 1 STEP 0.2 0 ;
+              END
+            END INV
+        ");
+        let makcro = &parser.macros["INV"];
+        assert_eq!(makcro.class, Some(LefMacroClass::CORE(None)));
+        assert_eq!(makcro.foreign[0].point, Some((0.0, 0.0)));
+        assert_eq!(makcro.size, Some((0.38, 1.4)));
+        assert_eq!(makcro.sites[0].name, "core");
+
+        let pin = &makcro.pins[0];
+        assert_eq!(pin.direction, Some(LefPinDirection::Output(true)));
+        assert_eq!(pin.antenna.diff_area[0].layer.as_deref(), Some("metal1"));
+        let oxides: Vec<_> = pin.antenna.models.iter().map(|model| model.oxide.as_str()).collect();
+        assert_eq!(oxides, ["OXIDE1", "OXIDE2"]);
+        assert_eq!(pin.antenna.models[1].gate_area[0].value, 0.2);
+        let port = &pin.ports[0];
+        assert_eq!(port.class, Some(LefPortClass::Default));
+        assert_eq!(port.geometries[0].geometries, [LefGeometry::Rect((0.0, 0.0), (0.1, 0.5), None)]);
+        assert_eq!((port.vias[0].via_name.as_str(), port.vias[0].location), ("via1", (0.05, 0.05)));
+
+        assert_eq!(makcro.obs.len(), 1);
+        assert!(makcro.obs_vias[0].step_pattern.is_some());
+        let obs = &makcro.obs[0];
+        assert_eq!(obs.geometries[0], LefGeometry::Path(Some(0.07), vec![(0.0, 0.0), (0.0, 1.0)], None));
+        assert!(matches!(obs.geometries[1], LefGeometry::Iterate(..)));
+    }
+
+    #[test]
+    fn reports_line_of_errors() {
+        let Err(err) = LefParser::parse_str("VERSION 5.8 ;\n\nFOO ;", true) else {
+            panic!("unknown statement accepted");
+        };
+        assert_eq!(err.to_string(), "line 3: unknown statement 'FOO'");
+        assert!(LefParser::parse_str("VERSION 6.0 ;", true).is_err());
+        assert!(LefParser::parse_str("MACRO A\nEND B", true).is_err());
+    }
+
+    #[test]
+    fn types_macro_properties() {
+        let parser = parse("
+            PROPERTYDEFINITIONS
+              MACRO cnt INTEGER ;
+              MACRO weight REAL ;
+            END PROPERTYDEFINITIONS
+            MACRO A
+              PROPERTY cnt 2 ;
+            END A
+            MACRO B
+              PROPERTY cnt 2.7 weight 3 ;
+            END B
+        ");
+        assert_eq!(parser.macros["A"].properties["cnt"], LefPropertyValue::Int(2));
+        assert_eq!(parser.macros["B"].properties["cnt"], LefPropertyValue::Real(2.7));
+        assert_eq!(parser.macros["B"].properties["weight"], LefPropertyValue::Real(3.0));
+    }
+
+    #[test]
+    fn rejects_geometry_before_layer() {
+        for statement in ["WIDTH 0.1 ;", "RECT 0 0 1 1 ;", "PATH 0 0 1 0 ;", "POLYGON 0 0 1 0 1 1 ;"] {
+            let input = format!("MACRO A\n  OBS\n    VIA 0 0 via1 ;\n    {}\n  END\nEND A", statement);
+            let Err(err) = LefParser::parse_str(&input, false) else {
+                panic!("{} accepted before LAYER", statement);
+            };
+            assert_eq!(err.to_string(), "line 4: geometry defined before any LAYER");
+        }
+        let input = "MACRO A\n  PIN Z\n    PORT\n      RECT 0 0 1 1 ;\n    END\n  END Z\nEND A";
+        assert!(LefParser::parse_str(input, false).is_err());
+    }
+
+    #[test]
+    fn loads_bench_files() {
+        let tech = LefTechnology::load_file_native(bench("tech")).unwrap();
+        assert_eq!(tech.version, Some(5.6));
+        assert!(!tech.layers.is_empty());
+        assert!(!tech.vias.is_empty());
+
+        for name in ["macro", "macro.mod", "macro.rect"] {
+            let library = LefCellLibrary::load_file_native(bench(name)).unwrap();
+            assert!(library.macros.len() >= 134, "{}", name);
+            assert!(library.macros.values().all(|makcro| !makcro.pins.is_empty()), "{}", name);
+        }
+    }
+
+    #[test]
+    fn loads_conformance_fixtures() {
+        let fixture = |name: &str| format!("{}/tests/data/{}.lef", env!("CARGO_MANIFEST_DIR"), name);
+
+        let tech = LefTechnology::load_file_native(fixture("constructs")).unwrap();
+        assert!(tech.fixed_mask);
+        assert_eq!(tech.non_default_rules["double_width"].min_cuts, [("via1".to_string(), 2)]);
+        let library = LefCellLibrary::load_file_native(fixture("constructs")).unwrap();
+        assert_eq!(library.macros["INV"].obs_vias[0].via_name, "via1_fixed");
+
+        let tech = LefTechnology::load_file_native(fixture("legacy")).unwrap();
+        assert_eq!(tech.noise_table.unwrap().edges.len(), 2);
+        assert!(tech.arrays.contains_key("gate_array"));
+        let library = LefCellLibrary::load_file_native(fixture("legacy")).unwrap();
+        assert_eq!(library.macros["BUF"].timings.len(), 1);
+    }
+}
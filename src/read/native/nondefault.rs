@@ -0,0 +1,78 @@
+use crate::{LefNonDefaultRule, LefNonDefaultRuleLayer, LefPropertyObjectType};
+use super::{LefParser, LefReadResult};
+
+impl LefParser {
+    pub(super) fn parse_non_default_rule(&mut self) -> LefReadResult<()> {
+        let mut rule = LefNonDefaultRule { name: self.name()?, ..Default::default() };
+
+        while !self.at("END") {
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "HARDSPACING" => {
+                    rule.hard_spacing = true;
+                    self.end_statement()?;
+                }
+                "LAYER" => {
+                    let layer = self.parse_non_default_rule_layer()?;
+                    rule.layers.push(layer);
+                }
+                "VIA" => {
+                    let (name, via) = self.parse_via()?;
+                    rule.vias.insert(name, via);
+                }
+                "USEVIA" => {
+                    rule.use_vias.push(self.name()?);
+                    self.end_statement()?;
+                }
+                "USEVIARULE" => {
+                    rule.use_via_rules.push(self.name()?);
+                    self.end_statement()?;
+                }
+                "MINCUTS" => {
+                    let cut_layer = self.name()?;
+                    rule.min_cuts.push((cut_layer, self.number()? as u32));
+                    self.end_statement()?;
+                }
+                "PROPERTY" => self.parse_properties(LefPropertyObjectType::NonDefaultRule, &mut rule.properties)?,
+                _ => return Err(self.error(format!("unknown NONDEFAULTRULE statement '{}'", keyword))),
+            }
+        }
+        // The name after END is optional
+        self.expect("END")?;
+        if self.peek().is_some_and(|token| token.text == rule.name) {
+            self.next_token()?;
+        }
+
+        self.tech.non_default_rules.insert(rule.name.clone(), rule);
+        Ok(())
+    }
+
+    fn parse_non_default_rule_layer(&mut self) -> LefReadResult<LefNonDefaultRuleLayer> {
+        let mut layer = LefNonDefaultRuleLayer { name: self.name()?, ..Default::default() };
+
+        while !self.at("END") {
+            let keyword = self.keyword()?;
+            let field = match keyword.as_str() {
+                "WIDTH" => &mut layer.width,
+                "DIAGWIDTH" => &mut layer.diag_width,
+                "SPACING" => &mut layer.spacing,
+                "WIREEXTENSION" => &mut layer.wire_extension,
+                "RESISTANCE" => {
+                    self.expect("RPERSQ")?;
+                    &mut layer.resistance
+                }
+                "CAPACITANCE" => {
+                    self.expect("CPERSQDIST")?;
+                    &mut layer.capacitance
+                }
+                "EDGECAPACITANCE" => &mut layer.edge_capacitance,
+                _ => return Err(self.error(format!("unknown NONDEFAULTRULE LAYER statement '{}'", keyword))),
+            };
+            *field = Some(self.number()?);
+            self.end_statement()?;
+        }
+        self.expect_end(&layer.name)?;
+
+        Ok(layer)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{LefPropertyDefinition, LefPropertyObjectType, LefPropertyType, LefPropertyValue};
+use crate::read::property::{integer_value, property_value};
+use super::{format_number, parse_number, LefParser, LefReadResult};
+use super::lexer::TokenKind;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+impl LefParser {
+    pub(super) fn parse_property_definitions(&mut self) -> LefReadResult<()> {
+        while !self.accept("END") {
+            let definition = self.parse_property_definition()?;
+            self.tech.property_definitions.push(definition);
+        }
+        self.expect("PROPERTYDEFINITIONS")
+    }
+
+    fn parse_property_definition(&mut self) -> LefReadResult<LefPropertyDefinition> {
+        let object_type = self.name()?;
+        let object_type = LefPropertyObjectType::from_str(&object_type.to_ascii_uppercase())
+            .map_err(|_| self.error(format!("unknown property object type '{}'", object_type)))?;
+        let name = self.name()?;
+
+        let data_type = self.keyword()?;
+        let (property_type, range, default) = match data_type.as_str() {
+            "INTEGER" | "REAL" => {
+                let range = if self.accept("RANGE") {
+                    Some((self.number()?, self.number()?))
+                } else {
+                    None
+                };
+                let number = if self.at_number() { Some(self.number()?) } else { None };
+                if data_type == "INTEGER" {
+                    (LefPropertyType::Integer, range, number.map(integer_value))
+                } else {
+                    (LefPropertyType::Real, range, number.map(LefPropertyValue::Real))
+                }
+            }
+            "STRING" => {
+                let value = if self.at(";") { None } else { Some(self.name()?) };
+                let default = value.filter(|value| !value.is_empty()).map(LefPropertyValue::String);
+                (LefPropertyType::String, None, default)
+            }
+            "NAMEMAPSTRING" => {
+                let value = self.name()?;
+                (LefPropertyType::String, None, Some(LefPropertyValue::String(value)))
+            }
+            _ => return Err(self.error(format!("unknown property type '{}'", data_type))),
+        };
+        self.end_statement()?;
+
+        Ok(LefPropertyDefinition { object_type, name, property_type, range, default })
+    }
+
+    /// Read the `name value` pairs of a PROPERTY statement, typed by the property definitions.
+    pub(super) fn parse_properties(&mut self, object_type: LefPropertyObjectType, properties: &mut HashMap<String, LefPropertyValue>) -> LefReadResult<()> {
+        while !self.accept(";") {
+            let name = self.name()?;
+            let token = self.next_token()?;
+            let number = match token.kind {
+                TokenKind::Word => parse_number(&token.text),
+                _ => None,
+            };
+            let value = match number {
+                Some(number) => format_number(number),
+                None => token.text,
+            };
+            let value = property_value(&self.tech.property_definitions, object_type, &name, number, value);
+            properties.insert(name, value);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{LefOrient, LefSite, LefSiteClass, LefSiteDefinition, LefStepPattern, LefSymmetry};
+use super::{LefParser, LefReadResult};
+use std::str::FromStr;
+
+impl LefParser {
+    pub(super) fn parse_site(&mut self) -> LefReadResult<()> {
+        let mut site = LefSiteDefinition { name: self.name()?, ..Default::default() };
+
+        while !self.at("END") {
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "SIZE" => {
+                    let width = self.number()?;
+                    self.expect("BY")?;
+                    site.size = (width, self.number()?);
+                    self.end_statement()?;
+                }
+                "SYMMETRY" => site.symmetry = self.parse_symmetry()?,
+                "CLASS" => {
+                    let class = self.keyword()?;
+                    site.class = LefSiteClass::from_str(&class)
+                        .map_err(|_| self.error(format!("unknown site class '{}'", class)))?;
+                    self.end_statement()?;
+                }
+                "ROWPATTERN" => {
+                    while !self.accept(";") {
+                        let name = self.name()?;
+                        if self.is_technology && !self.tech.sites.contains_key(&name) {
+                            return Err(self.error(format!("ROWPATTERN of site '{}' uses undefined site '{}'", site.name, name)));
+                        }
+                        let orient = self.name()?;
+                        let orient = parse_orient(&orient)
+                            .ok_or_else(|| self.error(format!("invalid orientation '{}' in ROWPATTERN of site '{}'", orient, site.name)))?;
+                        site.row_pattern.push((name, orient));
+                    }
+                }
+                _ => return Err(self.error(format!("unknown SITE statement '{}'", keyword))),
+            }
+        }
+        self.expect_end(&site.name)?;
+
+        self.tech.sites.insert(site.name.clone(), site);
+        Ok(())
+    }
+
+    /// Read the X, Y and R90 symmetries of a SYMMETRY statement.
+    pub(super) fn parse_symmetry(&mut self) -> LefReadResult<LefSymmetry> {
+        let mut symmetry = LefSymmetry::default();
+        while !self.accept(";") {
+            let keyword = self.keyword()?;
+            let axis = LefSymmetry::from_str(&keyword)
+                .map_err(|_| self.error(format!("unknown symmetry '{}'", keyword)))?;
+            symmetry = symmetry.union(axis);
+        }
+        Ok(symmetry)
+    }
+
+    /// Read a site pattern of a MACRO or ARRAY: `name x y orient [DO numX BY numY STEP stepX stepY]`.
+    pub(super) fn parse_site_pattern(&mut self) -> LefReadResult<LefSite> {
+        let name = self.name()?;
+        let origin = (self.number()?, self.number()?);
+        let orient = self.name()?;
+        let site_orient = parse_orient(&orient)
+            .ok_or_else(|| self.error(format!("invalid orientation '{}' of site '{}'", orient, name)))?;
+        let step_pattern = self.parse_step_pattern()?;
+        Ok(LefSite { name, origin, site_orient, step_pattern })
+    }
+
+    /// Read an optional `DO numX BY numY STEP stepX stepY`.
+    pub(super) fn parse_step_pattern(&mut self) -> LefReadResult<Option<LefStepPattern>> {
+        if !self.accept("DO") {
+            return Ok(None);
+        }
+        let x_start = self.number()?;
+        self.expect("BY")?;
+        let y_start = self.number()?;
+        self.expect("STEP")?;
+        let (x_step, y_step) = (self.number()?, self.number()?);
+        Ok(Some(LefStepPattern { x_step, y_step, x_start, y_start }))
+    }
+}
+
+/// Parse an orientation, including the R0/MX/... aliases Si2 accepts.
+pub(super) fn parse_orient(orient: &str) -> Option<LefOrient> {
+    let orient = orient.to_ascii_uppercase();
+    let orient = match orient.as_str() {
+        "R0" => "N",
+        "R90" => "W",
+        "R180" => "S",
+        "R270" => "E",
+        "MY" => "FN",
+        "MYR90" => "FW",
+        "MX" => "FS",
+        "MXR90" => "FE",
+        orient => orient,
+    };
+    LefOrient::from_str(orient).ok()
+}
@@ -0,0 +1,144 @@
+use crate::{LefSdfTrigger, LefTiming, LefTimingIntrinsic, LefTimingStable, LefTimingTable};
+use super::{LefParser, LefReadResult};
+use std::str::FromStr;
+
+impl LefParser {
+    /// Read a TIMING block up to END TIMING. Each FROMPIN starts a new timing, timings are only kept before LEF 5.4.
+    pub(super) fn parse_timing(&mut self, timings: &mut Vec<LefTiming>) -> LefReadResult<()> {
+        let mut timing = LefTiming::default();
+
+        loop {
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "END" => {
+                    self.expect("TIMING")?;
+                    break;
+                }
+                "FROMPIN" => {
+                    self.push_timing(timings, std::mem::take(&mut timing));
+                    while !self.accept(";") {
+                        timing.from_pins.push(self.name()?);
+                    }
+                }
+                "TOPIN" => {
+                    while !self.accept(";") {
+                        timing.to_pins.push(self.name()?);
+                    }
+                }
+                "RISE" | "FALL" => {
+                    let rise_fall = self.timing_keyword(keyword.clone())?;
+                    let kind = self.keyword()?;
+                    match kind.as_str() {
+                        "INTRINSIC" => {
+                            let intrinsic = (self.number()?, self.number()?);
+                            let mut slews = vec![];
+                            while self.at_number() {
+                                slews.push(self.number()?);
+                            }
+                            let (slew, slew2) = match slews[..] {
+                                [] => (None, None),
+                                [a, b, c, d] => (Some((a, b, c, d)), None),
+                                [a, b, c, d, e, f, g] => (Some((a, b, c, d)), Some((e, f, g))),
+                                _ => return Err(self.error("INTRINSIC requires 4 or 7 slew values")),
+                            };
+                            self.expect("VARIABLE")?;
+                            let variable = (self.number()?, self.number()?);
+                            self.end_statement()?;
+                            let intrinsic = Some(LefTimingIntrinsic { intrinsic, slew, slew2, variable });
+                            match keyword.as_str() {
+                                "RISE" => timing.rise_intrinsic = intrinsic,
+                                _ => timing.fall_intrinsic = intrinsic,
+                            }
+                        }
+                        "DELAY" | "TRANSITIONTIME" => {
+                            self.expect("UNATENESS")?;
+                            let unateness = self.keyword()?;
+                            let unateness = self.timing_keyword(unateness)?;
+                            let dimension = self.table_dimension()?;
+                            let table = Some(LefTimingTable { rise_fall, unateness, dimension });
+                            match kind.as_str() {
+                                "DELAY" => timing.delay = table,
+                                _ => timing.transition = table,
+                            }
+                        }
+                        _ => return Err(self.error(format!("invalid TIMING keyword '{}'", kind))),
+                    }
+                }
+                "TABLEAXIS" => timing.table_axis.extend(self.numbers_until_semicolon()?),
+                "TABLEENTRIES" => {
+                    while !self.accept(";") {
+                        self.expect("(")?;
+                        timing.table_entries.push((self.number()?, self.number()?, self.number()?));
+                        self.expect(")")?;
+                    }
+                }
+                "RISERS" | "FALLRS" | "RISECS" | "FALLCS" | "RISESATT1" | "FALLSATT1" | "RISET0" | "FALLT0" => {
+                    let pair = Some((self.number()?, self.number()?));
+                    self.end_statement()?;
+                    let field = match keyword.as_str() {
+                        "RISERS" => &mut timing.rise_rs,
+                        "FALLRS" => &mut timing.fall_rs,
+                        "RISECS" => &mut timing.rise_cs,
+                        "FALLCS" => &mut timing.fall_cs,
+                        "RISESATT1" => &mut timing.rise_satt1,
+                        "FALLSATT1" => &mut timing.fall_satt1,
+                        "RISET0" => &mut timing.rise_t0,
+                        _ => &mut timing.fall_t0,
+                    };
+                    *field = pair;
+                }
+                "UNATENESS" => {
+                    let unateness = self.keyword()?;
+                    timing.unateness = Some(self.timing_keyword(unateness)?);
+                    self.end_statement()?;
+                }
+                "STABLE" => {
+                    self.expect("SETUP")?;
+                    let setup = self.number()?;
+                    self.expect("HOLD")?;
+                    let hold = self.number()?;
+                    let rise_fall = self.keyword()?;
+                    let rise_fall = self.timing_keyword(rise_fall)?;
+                    self.end_statement()?;
+                    timing.stable = Some(LefTimingStable { setup, hold, rise_fall });
+                }
+                "SETUP" | "HOLD" | "RECOVERY" | "SKEW" => {
+                    let edges = Some((self.keyword()?, self.keyword()?));
+                    let dimension = self.table_dimension()?;
+                    timing.sdf_trigger = Some(LefSdfTrigger { trigger_type: keyword, edges, dimension });
+                }
+                "MPWH" | "MPWL" | "PERIOD" => {
+                    let dimension = self.table_dimension()?;
+                    timing.sdf_trigger = Some(LefSdfTrigger { trigger_type: keyword, edges: None, dimension });
+                }
+                "SDFCONDSTART" => timing.sdf_cond_start = Some(self.name_statement()?),
+                "SDFCONDEND" => timing.sdf_cond_end = Some(self.name_statement()?),
+                "SDFCOND" => timing.sdf_cond = Some(self.name_statement()?),
+                _ => return Err(self.error(format!("invalid TIMING keyword '{}'", keyword))),
+            }
+        }
+
+        self.push_timing(timings, timing);
+        Ok(())
+    }
+
+    /// Keep a timing that has FROMPINs, before LEF 5.4.
+    fn push_timing(&self, timings: &mut Vec<LefTiming>, timing: LefTiming) {
+        if self.version < 5.4 && !timing.from_pins.is_empty() {
+            timings.push(timing);
+        }
+    }
+
+    /// Read `TABLEDIMENSION a b c ;`.
+    fn table_dimension(&mut self) -> LefReadResult<(f64, f64, f64)> {
+        self.expect("TABLEDIMENSION")?;
+        let dimension = (self.number()?, self.number()?, self.number()?);
+        self.end_statement()?;
+        Ok(dimension)
+    }
+
+    /// Parse a RISE/FALL or unateness keyword of a TIMING statement.
+    fn timing_keyword<T: FromStr>(&self, keyword: String) -> LefReadResult<T> {
+        T::from_str(&keyword).map_err(|_| self.error(format!("invalid TIMING keyword '{}'", keyword)))
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{LefPropertyObjectType, LefRoutingDirection, LefVia, LefViaFixedRule, LefViaGenerateRule, LefViaLayer, LefViaRule, LefViaRuleLayer, LefViaRuleParams, LefViaShape};
+use super::{LefParser, LefReadResult};
+use std::collections::HashMap;
+
+/// LAYER of a VIARULE, either a routing layer or the cut layer of a VIARULE GENERATE.
+#[derive(Default)]
+struct ViaRuleLayer {
+    name: String,
+    direction: Option<LefRoutingDirection>,
+    enclosure: Option<(f64, f64)>,
+    width: Option<(f64, f64)>,
+    rect: Option<((f64, f64), (f64, f64))>,
+    spacing: Option<(f64, f64)>,
+}
+
+impl LefParser {
+    /// Read a VIA definition, returns its name and the via.
+    pub(super) fn parse_via(&mut self) -> LefReadResult<(String, LefVia)> {
+        let name = self.name()?;
+        let mut via = LefVia { is_default: self.accept("DEFAULT"), ..Default::default() };
+        self.accept("GENERATED");
+
+        // Rectangles come before polygons on each layer
+        let mut layers: Vec<(String, Vec<LefViaShape>, Vec<LefViaShape>)> = vec![];
+        let mut properties = HashMap::new();
+
+        while !self.at("END") {
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "RESISTANCE" => {
+                    via.resistance = Some(self.number()?);
+                    self.end_statement()?;
+                }
+                "LAYER" => {
+                    layers.push((self.name()?, vec![], vec![]));
+                    self.end_statement()?;
+                }
+                "RECT" | "POLYGON" => {
+                    let mask = self.mask()?;
+                    let points = self.points_until_semicolon()?;
+                    let Some((_, rects, polygons)) = layers.last_mut() else {
+                        return Err(self.error(format!("{} of via '{}' defined before any LAYER", keyword, name)));
+                    };
+                    if keyword == "RECT" {
+                        let [p1, p2] = points[..] else {
+                            return Err(self.error(format!("RECT of via '{}' requires two points", name)));
+                        };
+                        rects.push(LefViaShape::Rect(p1, p2, mask));
+                    } else {
+                        polygons.push(LefViaShape::Polygon(points, mask));
+                    }
+                }
+                "VIARULE" => {
+                    via_rule_params(&mut via).rule_name = self.name()?;
+                    self.end_statement()?;
+                }
+                "CUTSIZE" => {
+                    via_rule_params(&mut via).cut_size = self.point()?;
+                    self.end_statement()?;
+                }
+                "LAYERS" => {
+                    via_rule_params(&mut via).layers = (self.name()?, self.name()?, self.name()?);
+                    self.end_statement()?;
+                }
+                "CUTSPACING" => {
+                    via_rule_params(&mut via).cut_spacing = self.point()?;
+                    self.end_statement()?;
+                }
+                "ENCLOSURE" => {
+                    via_rule_params(&mut via).enclosure = (self.point()?, self.point()?);
+                    self.end_statement()?;
+                }
+                "ROWCOL" => {
+                    via_rule_params(&mut via).row_col = (self.number()? as u32, self.number()? as u32);
+                    self.end_statement()?;
+                }
+                "ORIGIN" => {
+                    via_rule_params(&mut via).origin = self.point()?;
+                    self.end_statement()?;
+                }
+                "OFFSET" => {
+                    via_rule_params(&mut via).offset = (self.point()?, self.point()?);
+                    self.end_statement()?;
+                }
+                "PATTERN" => {
+                    via_rule_params(&mut via).pattern = Some(self.name()?);
+                    self.end_statement()?;
+                }
+                "PROPERTY" => self.parse_properties(LefPropertyObjectType::Via, &mut properties)?,
+                "FOREIGN" | "TOPOFSTACKONLY" => self.skip_statement()?,
+                _ => return Err(self.error(format!("unknown VIA statement '{}'", keyword))),
+            }
+        }
+        self.expect_end(&name)?;
+
+        via.geometry = layers
+            .into_iter()
+            .map(|(name, rects, polygons)| LefViaLayer { name, shapes: rects.into_iter().chain(polygons).collect() })
+            .collect();
+        via.properties = properties;
+        Ok((name, via))
+    }
+
+    /// Read the optional MASK of a shape. Masks are ignored before LEF 5.8.
+    pub(super) fn mask(&mut self) -> LefReadResult<Option<u32>> {
+        if !self.accept("MASK") {
+            return Ok(None);
+        }
+        let mask = self.number()? as u32;
+        Ok(if self.version >= 5.8 && mask > 0 { Some(mask) } else { None })
+    }
+
+    pub(super) fn parse_via_rule(&mut self) -> LefReadResult<()> {
+        let rule_name = self.name()?;
+        let generate = self.accept("GENERATE");
+        let is_default = generate && self.accept("DEFAULT");
+
+        let mut layers: Vec<ViaRuleLayer> = vec![];
+        let mut vias = vec![];
+        while !self.at("END") {
+            let keyword = self.keyword()?;
+            match keyword.as_str() {
+                "LAYER" => {
+                    layers.push(ViaRuleLayer { name: self.name()?, ..Default::default() });
+                    self.end_statement()?;
+                }
+                "VIA" => {
+                    vias.push(self.name()?);
+                    self.end_statement()?;
+                }
+                "PROPERTY" => self.skip_statement()?,
+                _ => {
+                    let Some(layer) = layers.last_mut() else {
+                        return Err(self.error(format!("{} of VIARULE '{}' defined before any LAYER", keyword, rule_name)));
+                    };
+                    match keyword.as_str() {
+                        "DIRECTION" => {
+                            let direction = self.keyword()?;
+                            layer.direction = match direction.as_str() {
+                                "HORIZONTAL" => Some(LefRoutingDirection::Horizontal),
+                                "VERTICAL" => Some(LefRoutingDirection::Vertical),
+                                _ => None,
+                            };
+                        }
+                        "ENCLOSURE" => layer.enclosure = Some((self.number()?, self.number()?)),
+                        "WIDTH" => {
+                            let min = self.number()?;
+                            self.expect("TO")?;
+                            layer.width = Some((min, self.number()?));
+                        }
+                        "RECT" => layer.rect = Some((self.point()?, self.point()?)),
+                        "SPACING" => {
+                            let x = self.number()?;
+                            self.expect("BY")?;
+                            layer.spacing = Some((x, self.number()?));
+                        }
+                        "RESISTANCE" | "OVERHANG" | "METALOVERHANG" => {
+                            self.number()?;
+                        }
+                        _ => return Err(self.error(format!("unknown VIARULE statement '{}'", keyword))),
+                    }
+                    self.end_statement()?;
+                }
+            }
+        }
+        self.expect_end(&rule_name)?;
+
+        let via_rule = if generate {
+            via_rule_generate(rule_name.clone(), is_default, layers).map_err(|msg| self.error(msg))?
+        } else {
+            via_rule_fixed(rule_name.clone(), layers, vias).map_err(|msg| self.error(msg))?
+        };
+        self.tech.via_rules.insert(rule_name, via_rule);
+        Ok(())
+    }
+}
+
+fn via_rule_params(via: &mut LefVia) -> &mut LefViaRuleParams {
+    via.via_rule.get_or_insert_with(Default::default)
+}
+
+fn via_rule_generate(rule_name: String, is_default: bool, layers: Vec<ViaRuleLayer>) -> Result<LefViaRule, String> {
+    let mut via_rule = LefViaGenerateRule { is_default, rule_name, ..Default::default() };
+
+    if layers.len() != 3 {
+        return Err(format!("VIARULE GENERATE '{}' requires three layers", via_rule.rule_name));
+    }
+
+    // The cut layer is the one with RECT/SPACING, the routing layers keep their order in the file
+    let Some(cut_index) = layers.iter().position(|layer| layer.rect.is_some() || layer.spacing.is_some()) else {
+        return Err(format!("VIARULE GENERATE '{}' has no cut layer", via_rule.rule_name));
+    };
+    let mut layers = layers;
+    let cut = layers.remove(cut_index);
+    let mut routing = layers.into_iter();
+    let bottom = routing.next().unwrap();
+    let top = routing.next().unwrap();
+
+    // ENCLOSURE
+    if let Some(enclosure) = bottom.enclosure {
+        via_rule.enclosure.0 = enclosure;
+    }
+    if let Some(enclosure) = top.enclosure {
+        via_rule.enclosure.1 = enclosure;
+    }
+
+    // WIDTH
+    if let Some(width) = bottom.width {
+        via_rule.width.0 = width;
+    }
+    if let Some(width) = top.width {
+        via_rule.width.1 = width;
+    }
+
+    // RECT
+    if let Some(rect) = cut.rect {
+        via_rule.rect = rect;
+    }
+
+    // SPACING
+    if let Some(spacing) = cut.spacing {
+        via_rule.spacing = spacing;
+    }
+
+    // LAYERS
+    via_rule.layers = (bottom.name, cut.name, top.name);
+
+    Ok(LefViaRule::Generate(via_rule))
+}
+
+fn via_rule_fixed(rule_name: String, layers: Vec<ViaRuleLayer>, vias: Vec<String>) -> Result<LefViaRule, String> {
+    let Ok([first, second]) = <[ViaRuleLayer; 2]>::try_from(layers) else {
+        return Err(format!("VIARULE '{}' requires two layers", rule_name));
+    };
+    let layer = |layer: ViaRuleLayer| LefViaRuleLayer { name: layer.name, direction: layer.direction, width: layer.width };
+
+    Ok(LefViaRule::Fixed(LefViaFixedRule { rule_name, layers: (layer(first), layer(second)), vias }))
+}
@@ -0,0 +1,68 @@
+use crate::{find_property_definition, LefPropertyDefinition, LefPropertyObjectType, LefPropertyType, LefPropertyValue};
+
+/// Type a property value with its definition. Without definition numbers are stored as `Real`.
+pub fn property_value(definitions: &[LefPropertyDefinition], object_type: LefPropertyObjectType, name: &str, number: Option<f64>, value: String) -> LefPropertyValue {
+    let property_type = find_property_definition(definitions, object_type, name)
+        .map(|definition| definition.property_type);
+
+    match (property_type, number) {
+        (Some(LefPropertyType::Integer), Some(number)) => integer_value(number),
+        (Some(LefPropertyType::Integer), None) => match value.parse() {
+            Ok(number) => LefPropertyValue::Int(number),
+            Err(_) => LefPropertyValue::String(value),
+        },
+        (Some(LefPropertyType::Real), Some(number)) => LefPropertyValue::Real(number),
+        (Some(LefPropertyType::Real), None) => match value.parse() {
+            Ok(number) => LefPropertyValue::Real(number),
+            Err(_) => LefPropertyValue::String(value),
+        },
+        (Some(LefPropertyType::String), _) => LefPropertyValue::String(value),
+        (None, Some(number)) => LefPropertyValue::Real(number),
+        (None, None) => LefPropertyValue::String(value),
+    }
+}
+
+/// Value of an INTEGER property. Numbers with a fraction or outside the `i32` range are kept as `Real`
+/// instead of being truncated.
+pub fn integer_value(number: f64) -> LefPropertyValue {
+    if number.fract() == 0.0 && number >= i32::MIN as f64 && number <= i32::MAX as f64 {
+        LefPropertyValue::Int(number as i32)
+    } else {
+        LefPropertyValue::Real(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definitions() -> Vec<LefPropertyDefinition> {
+        vec![LefPropertyDefinition {
+            object_type: LefPropertyObjectType::Macro,
+            name: "cnt".to_string(),
+            property_type: LefPropertyType::Integer,
+            range: None,
+            default: None,
+        }]
+    }
+
+    #[test]
+    fn keeps_integer_properties_exact() {
+        let value = |number: f64| property_value(&definitions(), LefPropertyObjectType::Macro, "cnt", Some(number), number.to_string());
+        assert_eq!(value(2.0), LefPropertyValue::Int(2));
+        assert_eq!(value(-7.0), LefPropertyValue::Int(-7));
+        assert_eq!(value(2.7), LefPropertyValue::Real(2.7));
+        assert_eq!(value(1e10), LefPropertyValue::Real(1e10));
+    }
+
+    #[test]
+    fn types_properties_by_definition() {
+        let definitions = definitions();
+        let value = |object_type, name: &str, number, value: &str| property_value(&definitions, object_type, name, number, value.to_string());
+        assert_eq!(value(LefPropertyObjectType::Macro, "cnt", None, "3"), LefPropertyValue::Int(3));
+        assert_eq!(value(LefPropertyObjectType::Macro, "cnt", None, "x"), LefPropertyValue::String("x".to_string()));
+        // Without a definition for the object type numbers are `Real`
+        assert_eq!(value(LefPropertyObjectType::Pin, "cnt", Some(3.0), "3"), LefPropertyValue::Real(3.0));
+        assert_eq!(value(LefPropertyObjectType::Pin, "cnt", None, "x"), LefPropertyValue::String("x".to_string()));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{LefArray, LefFloorPlan, LefGcellPattern, LefTrackPattern};
+use super::{LefReadError, LefReadResult, LefTechnologyReader};
+use crate::si2;
+use super::utils;
+use super::site::read_site_pattern;
+use std::os::raw::{c_int, c_void};
+
+impl LefTechnologyReader {
+    pub unsafe extern "C" fn read_array(_: si2::lefrCallbackType_e, obj: *mut si2::lefiArray, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            match do_read_array(obj) {
+                Ok(array) => {
+                    reader.lef.arrays.insert(array.name.clone(), array);
+                }
+                Err(err) => {
+                    reader.error = Some(err);
+                    return 1;
+                }
+            }
+        }
+        0
+    }
+}
+
+unsafe fn do_read_array(obj: *mut si2::lefiArray) -> LefReadResult<LefArray> {
+    unsafe {
+        let mut array = LefArray::default();
+        array.name = utils::const_c_char_ptr_to_string(si2::lefiArray_name(obj));
+
+        // SITE / CANPLACE / CANNOTOCCUPY
+        for index in 0..si2::lefiArray_numSitePattern(obj) {
+            array.sites.push(read_site_pattern(si2::lefiArray_sitePattern(obj, index))?);
+        }
+        for index in 0..si2::lefiArray_numCanPlace(obj) {
+            array.can_place.push(read_site_pattern(si2::lefiArray_canPlace(obj, index))?);
+        }
+        for index in 0..si2::lefiArray_numCannotOccupy(obj) {
+            array.cannot_occupy.push(read_site_pattern(si2::lefiArray_cannotOccupy(obj, index))?);
+        }
+
+        // TRACKS
+        for index in 0..si2::lefiArray_numTrack(obj) {
+            let track_obj = si2::lefiArray_track(obj, index);
+            let mut track = LefTrackPattern::default();
+            track.name = utils::const_c_char_ptr_to_string(si2::lefiTrackPattern_name(track_obj));
+            track.start = si2::lefiTrackPattern_start(track_obj);
+            track.num_tracks = si2::lefiTrackPattern_numTracks(track_obj) as u32;
+            track.space = si2::lefiTrackPattern_space(track_obj);
+            for layer_index in 0..si2::lefiTrackPattern_numLayers(track_obj) {
+                track.layers.push(utils::const_c_char_ptr_to_string(si2::lefiTrackPattern_layerName(track_obj, layer_index)));
+            }
+            array.tracks.push(track);
+        }
+
+        // GCELLGRID
+        for index in 0..si2::lefiArray_numGcell(obj) {
+            let gcell_obj = si2::lefiArray_gcell(obj, index);
+            array.gcell_grids.push(LefGcellPattern {
+                name: utils::const_c_char_ptr_to_string(si2::lefiGcellPattern_name(gcell_obj)),
+                start: si2::lefiGcellPattern_start(gcell_obj),
+                num_crs: si2::lefiGcellPattern_numCRs(gcell_obj) as u32,
+                space: si2::lefiGcellPattern_space(gcell_obj),
+            });
+        }
+
+        // DEFAULTCAP
+        for index in 0..si2::lefiArray_numDefaultCaps(obj) {
+            array.default_caps.push((si2::lefiArray_defaultCapMinPins(obj, index) as u32, si2::lefiArray_defaultCap(obj, index)));
+        }
+
+        // FLOORPLAN
+        for floor_index in 0..si2::lefiArray_numFloorPlans(obj) {
+            let mut floor_plan = LefFloorPlan::default();
+            floor_plan.name = utils::const_c_char_ptr_to_string(si2::lefiArray_floorPlanName(obj, floor_index));
+            for site_index in 0..si2::lefiArray_numSites(obj, floor_index) {
+                let site = read_site_pattern(si2::lefiArray_site(obj, floor_index, site_index))?;
+                match utils::const_c_char_ptr_to_str(si2::lefiArray_siteType(obj, floor_index, site_index)) {
+                    "CANPLACE" => floor_plan.can_place.push(site),
+                    "CANNOTOCCUPY" => floor_plan.cannot_occupy.push(site),
+                    site_type => return Err(LefReadError::Msg(format!("unknown site type '{}' in FLOORPLAN '{}'", site_type, floor_plan.name))),
+                }
+            }
+            array.floor_plans.push(floor_plan);
+        }
+
+        Ok(array)
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{LefCrossTalkEdge, LefCrossTalkResistance, LefCrossTalkTable, LefCrossTalkVictim, LefIrDropTable, LefNoiseMargin};
+use super::LefTechnologyReader;
+use crate::si2;
+use super::utils;
+use std::os::raw::{c_int, c_void};
+use paste::paste;
+
+/// Read a NOISETABLE or CORRECTIONTABLE, which only differ by the name of the victim values.
+macro_rules! read_cross_talk_table {
+    ($si2_obj:ident, $kind:ident, $num_values:ident, $value:ident) => {
+        paste! {
+            {
+                let mut table = LefCrossTalkTable::default();
+                table.number = si2::[< lefi $kind Table_num >]($si2_obj);
+                for edge_index in 0..si2::[< lefi $kind Table_numEdges >]($si2_obj) {
+                    let edge_obj = si2::[< lefi $kind Table_edge >]($si2_obj, edge_index);
+                    let mut edge = LefCrossTalkEdge::default();
+                    edge.edge_rate = si2::[< lefi $kind Edge_edge >](edge_obj);
+                    for resistance_index in 0..si2::[< lefi $kind Edge_numResistances >](edge_obj) {
+                        let resistance_obj = si2::[< lefi $kind Edge_resistance >](edge_obj, resistance_index);
+                        let mut resistance = LefCrossTalkResistance::default();
+                        for index in 0..si2::[< lefi $kind Resistance_numNums >](resistance_obj) {
+                            resistance.output_resistances.push(si2::[< lefi $kind Resistance_num >](resistance_obj, index));
+                        }
+                        for victim_index in 0..si2::[< lefi $kind Resistance_numVictims >](resistance_obj) {
+                            let victim_obj = si2::[< lefi $kind Resistance_victim >](resistance_obj, victim_index);
+                            let mut victim = LefCrossTalkVictim::default();
+                            victim.length = si2::[< lefi $kind Victim_length >](victim_obj);
+                            for index in 0..si2::[< lefi $kind Victim_ $num_values >](victim_obj) {
+                                victim.values.push(si2::[< lefi $kind Victim_ $value >](victim_obj, index));
+                            }
+                            resistance.victims.push(victim);
+                        }
+                        edge.resistances.push(resistance);
+                    }
+                    table.edges.push(edge);
+                }
+                table
+            }
+        }
+    };
+}
+
+impl LefTechnologyReader {
+    pub unsafe extern "C" fn read_ir_drop(_: si2::lefrCallbackType_e, obj: *mut si2::lefiIRDrop, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            let mut table = LefIrDropTable {
+                name: utils::const_c_char_ptr_to_string(si2::lefiIRDrop_name(obj)),
+                ..Default::default()
+            };
+            for index in 0..si2::lefiIRDrop_numValues(obj) {
+                table.values.push((si2::lefiIRDrop_value1(obj, index), si2::lefiIRDrop_value2(obj, index)));
+            }
+            reader.lef.ir_drop_tables.push(table);
+        }
+        0
+    }
+
+    pub unsafe extern "C" fn read_dielectric(_: si2::lefrCallbackType_e, number: f64, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.dielectric = Some(number);
+        }
+        0
+    }
+
+    pub unsafe extern "C" fn read_noise_margin(_: si2::lefrCallbackType_e, obj: *mut si2::lefiNoiseMargin, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.noise_margin = Some(LefNoiseMargin { high: (*obj).high, low: (*obj).low });
+        }
+        0
+    }
+
+    pub unsafe extern "C" fn read_edge_rate_threshold1(_: si2::lefrCallbackType_e, number: f64, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.edge_rate_threshold1 = Some(number);
+        }
+        0
+    }
+
+    pub unsafe extern "C" fn read_edge_rate_threshold2(_: si2::lefrCallbackType_e, number: f64, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.edge_rate_threshold2 = Some(number);
+        }
+        0
+    }
+
+    pub unsafe extern "C" fn read_edge_rate_scale_factor(_: si2::lefrCallbackType_e, number: f64, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.edge_rate_scale_factor = Some(number);
+        }
+        0
+    }
+
+    pub unsafe extern "C" fn read_noise_table(_: si2::lefrCallbackType_e, obj: *mut si2::lefiNoiseTable, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.noise_table = Some(read_cross_talk_table!(obj, Noise, numNoises, noise));
+        }
+        0
+    }
+
+    pub unsafe extern "C" fn read_correction_table(_: si2::lefrCallbackType_e, obj: *mut si2::lefiCorrectionTable, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.correction_table = Some(read_cross_talk_table!(obj, Correction, numCorrections, correction));
+        }
+        0
+    }
+}
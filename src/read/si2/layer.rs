@@ -1,5 +1,7 @@
-use crate::{LefCutLayer, LefCutSpacingRule, LefLayer, LefPropertyValue, LefRoutingDirection, LefRoutingLayer, LefSpacingRules, LefSpacingTable, LefSpacingType};
+use crate::{LefCutLayer, LefCutSpacingRule, LefLayer, LefPropertyDefinition, LefPropertyObjectType, LefRoutingDirection, LefRoutingLayer, LefSpacingParallelEdge, LefSpacingRangeType, LefSpacingRules, LefSpacingTable, LefSpacingType};
 use super::{LefReadResult, LefTechnologyReader};
+use super::property::read_properties;
+use crate::read::lef58;
 use crate::si2;
 use super::utils;
 use std::{os::raw::{c_int, c_void}, str::FromStr};
@@ -48,7 +50,7 @@ impl LefTechnologyReader {
             let tpe =  utils::const_c_char_ptr_to_str(si2::lefiLayer_type(obj));
             match tpe {
                 "CUT" => {
-                    match Self::read_cut_layer(obj) {
+                    match Self::read_cut_layer(obj, &reader.lef.property_definitions) {
                         Ok(layer) => reader.lef.layers.push(LefLayer::Cut(layer)),
                         Err(err) => { 
                             reader.error = Some(err);
@@ -57,7 +59,7 @@ impl LefTechnologyReader {
                     }
                 }
                 "ROUTING" => {
-                    match Self::read_routing_layer(obj) {
+                    match Self::read_routing_layer(obj, &reader.lef.property_definitions) {
                         Ok(layer) => reader.lef.layers.push(LefLayer::Routing(layer)),
                         Err(err) => { 
                             reader.error = Some(err);
@@ -77,7 +79,7 @@ impl LefTechnologyReader {
         0
     }
 
-    unsafe fn read_routing_layer(obj: *mut si2::lefiLayer) -> LefReadResult<LefRoutingLayer> {
+    unsafe fn read_routing_layer(obj: *mut si2::lefiLayer, definitions: &[LefPropertyDefinition]) -> LefReadResult<LefRoutingLayer> {
         unsafe {
             let mut layer = LefRoutingLayer::default();
             layer.name = utils::const_c_char_ptr_to_string(si2::lefiLayer_name(obj));
@@ -146,12 +148,45 @@ impl LefTechnologyReader {
                     let spacing_type = if si2::lefiLayer_hasSpacingRange(obj, index) != 0 {
                         let min_width = si2::lefiLayer_spacingRangeMin(obj, index);
                         let max_width = si2::lefiLayer_spacingRangeMax(obj, index);
-                        // TODO: range type
-                        Some(LefSpacingType::Range { min_width, max_width, spacing_range_type: None })
+                        let spacing_range_type = if si2::lefiLayer_hasSpacingRangeUseLengthThreshold(obj, index) != 0 {
+                            Some(LefSpacingRangeType::UseLengthThreshold)
+                        } else if si2::lefiLayer_hasSpacingRangeInfluence(obj, index) != 0 {
+                            let influence_length = si2::lefiLayer_spacingRangeInfluence(obj, index);
+                            let stub_range = if si2::lefiLayer_hasSpacingRangeInfluenceRange(obj, index) != 0 {
+                                Some((si2::lefiLayer_spacingRangeInfluenceMin(obj, index), si2::lefiLayer_spacingRangeInfluenceMax(obj, index)))
+                            } else {
+                                None
+                            };
+                            Some(LefSpacingRangeType::Influence { influence_length, stub_range })
+                        } else if si2::lefiLayer_hasSpacingRangeRange(obj, index) != 0 {
+                            let min_width = si2::lefiLayer_spacingRangeRangeMin(obj, index);
+                            let max_width = si2::lefiLayer_spacingRangeRangeMax(obj, index);
+                            Some(LefSpacingRangeType::Range { min_width, max_width })
+                        } else {
+                            None
+                        };
+                        Some(LefSpacingType::Range { min_width, max_width, spacing_range_type })
+                    } else if si2::lefiLayer_hasSpacingLengthThreshold(obj, index) != 0 {
+                        let max_length = si2::lefiLayer_spacingLengthThreshold(obj, index);
+                        let width_range = if si2::lefiLayer_hasSpacingLengthThresholdRange(obj, index) != 0 {
+                            Some((si2::lefiLayer_spacingLengthThresholdRangeMin(obj, index), si2::lefiLayer_spacingLengthThresholdRangeMax(obj, index)))
+                        } else {
+                            None
+                        };
+                        Some(LefSpacingType::LengthThreshold { max_length, width_range })
                     } else if si2::lefiLayer_hasSpacingEndOfLine(obj, index) != 0{
                         let eol_width = si2::lefiLayer_spacingEolWidth(obj, index);
                         let eol_widthing = si2::lefiLayer_spacingEolWithin(obj, index);
-                        Some(LefSpacingType::EndOfLine { eol_width, eol_widthing })
+                        let parallel_edge = if si2::lefiLayer_hasSpacingParellelEdge(obj, index) != 0 {
+                            Some(LefSpacingParallelEdge {
+                                par_space: si2::lefiLayer_spacingParSpace(obj, index),
+                                par_within: si2::lefiLayer_spacingParWithin(obj, index),
+                                two_edges: si2::lefiLayer_hasSpacingTwoEdges(obj, index) != 0,
+                            })
+                        } else {
+                            None
+                        };
+                        Some(LefSpacingType::EndOfLine { eol_width, eol_widthing, parallel_edge })
                     } else if si2::lefiLayer_hasSpacingSamenet(obj, index) != 0{
                         let power_ground_only = si2::lefiLayer_hasSpacingSamenetPGonly(obj, index) != 0;
                         Some(LefSpacingType::SameNet { power_ground_only })
@@ -163,6 +198,9 @@ impl LefTechnologyReader {
                         let min_notch_spacing = si2::lefiLayer_spacingEndOfNotchSpacing(obj, index);
                         let min_notch_length = si2::lefiLayer_spacingEndOfNotchLength(obj, index);
                         Some(LefSpacingType::EndOfNotchWidth { end_of_notch_width, min_notch_spacing, min_notch_length })
+                    } else if si2::lefiLayer_hasSpacingArea(obj, index) != 0 {
+                        let min_area = si2::lefiLayer_spacingArea(obj, index);
+                        Some(LefSpacingType::Area { min_area })
                     } else {    
                         None
                     };
@@ -197,27 +235,22 @@ impl LefTechnologyReader {
                 }                
             }
     
-            for index in 0..si2::lefiLayer_numProps(obj) {
-                let prop = utils::const_c_char_ptr_to_string(si2::lefiLayer_propName(obj, index));
-                if si2::lefiLayer_propIsNumber(obj, index) != 0 {
-                    layer.properties.insert(prop, LefPropertyValue::Real(si2::lefiLayer_propNumber(obj, index)));
-                } else if si2::lefiLayer_propIsString(obj, index) != 0 {
-                    let value = utils::const_c_char_ptr_to_string(si2::lefiLayer_propValue(obj, index));
-                    layer.properties.insert(prop, LefPropertyValue::String(value));
-                }
-            }
+            // PROPERTY
+            layer.properties = read_properties!(obj, lefiLayer, numProps, propNumber, definitions, LefPropertyObjectType::Layer);
+            layer.lef58_rules = lef58::lef58_rules(&layer.properties);
 
             Ok(layer)
         }
     }
 
-    unsafe fn read_cut_layer(obj: *mut si2::lefiLayer) -> LefReadResult<LefCutLayer> {
+    unsafe fn read_cut_layer(obj: *mut si2::lefiLayer, definitions: &[LefPropertyDefinition]) -> LefReadResult<LefCutLayer> {
         unsafe {
             let mut layer = LefCutLayer::default();
             layer.name = utils::const_c_char_ptr_to_string(si2::lefiLayer_name(obj));
             
             layer_attr_opt!(layer, obj, mask_num, mask, u32);
             layer_attr_opt!(layer, obj, width, width);
+            layer_attr_opt!(layer, obj, resistance, resistancePerCut);
             layer_attr_opt!(layer, obj, resistance, resistance);
 
             if si2::lefiLayer_hasSpacingNumber(obj) != 0 {
@@ -231,6 +264,10 @@ impl LefTechnologyReader {
                 }
             }
 
+            // PROPERTY
+            layer.properties = read_properties!(obj, lefiLayer, numProps, propNumber, definitions, LefPropertyObjectType::Layer);
+            layer.lef58_rules = lef58::lef58_rules(&layer.properties);
+
             Ok(layer)
         }
     }
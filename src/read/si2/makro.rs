@@ -0,0 +1,483 @@
+use crate::{read::{LefReadError, LefReadResult}, LefAntennaValue, LefDensityLayer, LefDensityRect, LefForeign, LefGeometry, LefLayerGeometries, LefMacro, LefMacroFunction, LefMacroPin, LefMacroSource, LefNetExpr, LefOrient, LefPinAntennaModel, LefPort, LefPortClass, LefPropertyObjectType, LefPinDirection, LefPinShape, LefSignalUse, LefSite, LefSpacingOrDesignRuleWidth, LefStepPattern, LefSymmetry, LefViaInstance};
+use super::LefCellLibraryReader;
+use crate::si2;
+use super::utils;
+use super::property::read_properties;
+use super::site::read_site_pattern;
+use std::{os::raw::{c_int, c_void}, str::FromStr};
+use paste::paste;
+
+macro_rules! pin_attr_opt {
+    ($field:expr, $si2_obj:ident, $attr_name:ident, $getter:ident) => {
+        paste! {
+            if si2::[< lefiPin_has $attr_name >]($si2_obj) != 0 {
+                $field = Some(si2::[< lefiPin_ $getter >]($si2_obj));
+            }
+        }
+    };
+}
+
+/// Read the values and LAYER qualifiers of a pin antenna statement.
+macro_rules! antenna_values {
+    ($si2_obj:ident, $si2_type:ident, $attr_name:ident) => {
+        paste! {
+            (0..si2::[< $si2_type _numAntenna $attr_name >]($si2_obj))
+                .map(|index| LefAntennaValue {
+                    value: si2::[< $si2_type _antenna $attr_name >]($si2_obj, index),
+                    layer: utils::opt_const_c_char_ptr_to_string(si2::[< $si2_type _antenna $attr_name Layer >]($si2_obj, index)),
+                })
+                .collect::<Vec<_>>()
+        }
+    };
+}
+
+impl LefCellLibraryReader {
+    pub unsafe extern "C" fn read_macro(_: si2::lefrCallbackType_e, obj: *mut si2::lefiSite, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+
+            let mut makcro = LefMacro::default();
+            makcro.name = utils::const_c_char_ptr_to_string(si2::lefiMacro_name(obj));
+
+            // CLASS
+            if si2::lefiMacro_hasClass(obj) != 0 {
+                match FromStr::from_str(utils::const_c_char_ptr_to_str(si2::lefiMacro_macroClass(obj))) {
+                    Ok(class) => makcro.class = Some(class),
+                    Err(err) => {
+                        reader.error = Some(LefReadError::Msg(err));
+                        return 1;
+                    }
+                }
+            } 
+
+            // FOREIGN
+            for index in 0..si2::lefiMacro_numForeigns(obj) {
+                let mut foreign = LefForeign::default();
+                foreign.cell_name = utils::const_c_char_ptr_to_string(si2::lefiMacro_foreignName(obj, index));
+                if si2::lefiMacro_hasForeignPoint(obj, index) != 0 {
+                    foreign.point = Some((si2::lefiMacro_foreignX(obj, index), si2::lefiMacro_foreignY(obj, index)));
+                }
+                if si2::lefiMacro_hasForeignOrient(obj, index) != 0 {
+                    let orient = utils::const_c_char_ptr_to_str(si2::lefiMacro_foreignOrientStr(obj, index));
+                    match LefOrient::from_str(orient) {
+                        Ok(orient) => foreign.orient = Some(orient),
+                        Err(_) => {
+                            reader.error = Some(LefReadError::Msg(format!("invalid FOREIGN orientation '{}'", orient)));
+                            return 1;
+                        }
+                    }
+                }
+                makcro.foreign.push(foreign);
+            }
+
+            // FIXEDMASK
+            makcro.fixed_mask = si2::lefiMacro_isFixedMask(obj) != 0;
+
+            // ORIGIN
+            if si2::lefiMacro_hasOrigin(obj) != 0 {
+                makcro.origin = (si2::lefiMacro_originX(obj), si2::lefiMacro_originY(obj))
+            }
+
+            // EEQ
+            if si2::lefiMacro_hasEEQ(obj) != 0 {
+                makcro.eeq = Some(utils::const_c_char_ptr_to_string(si2::lefiMacro_EEQ(obj)));
+            }
+            
+            // LEQ
+            if si2::lefiMacro_hasLEQ(obj) != 0 {
+                makcro.leq = Some(utils::const_c_char_ptr_to_string(si2::lefiMacro_LEQ(obj)));
+            }
+
+            // SOURCE
+            if si2::lefiMacro_hasSource(obj) != 0 {
+                let source = utils::const_c_char_ptr_to_str(si2::lefiMacro_source(obj));
+                match LefMacroSource::from_str(source) {
+                    Ok(source) => makcro.source = Some(source),
+                    Err(_) => {
+                        reader.error = Some(LefReadError::Msg(format!("unknown macro source '{}'", source)));
+                        return 1;
+                    }
+                }
+            }
+
+            // POWER
+            if si2::lefiMacro_hasPower(obj) != 0 {
+                makcro.power = Some(si2::lefiMacro_power(obj));
+            }
+
+            // GENERATOR / GENERATE
+            if si2::lefiMacro_hasGenerator(obj) != 0 {
+                makcro.generator = Some(utils::const_c_char_ptr_to_string(si2::lefiMacro_generator(obj)));
+            }
+            if si2::lefiMacro_hasGenerate(obj) != 0 {
+                let mut name1 = std::ptr::null_mut();
+                let mut name2 = std::ptr::null_mut();
+                si2::lefiMacro_generate(obj, &mut name1, &mut name2);
+                makcro.generate = Some((utils::mut_c_char_ptr_to_string(name1), utils::mut_c_char_ptr_to_string(name2)));
+            }
+
+            // FUNCTION
+            if si2::lefiMacro_isBuffer(obj) != 0 {
+                makcro.function = Some(LefMacroFunction::BUFFER);
+            } else if si2::lefiMacro_isInverter(obj) != 0 {
+                makcro.function = Some(LefMacroFunction::INVERTER);
+            }
+
+            // CLOCKTYPE
+            if si2::lefiMacro_hasClockType(obj) != 0 {
+                makcro.clock_type = Some(utils::const_c_char_ptr_to_string(si2::lefiMacro_clockType(obj)));
+            }
+
+            // SIZE
+            if si2::lefiMacro_hasSize(obj) != 0 {
+                makcro.size = Some((si2::lefiMacro_sizeX(obj), si2::lefiMacro_sizeY(obj)));
+            }
+
+            // SYMMETRY
+            makcro.symmetry = LefSymmetry {
+                x: si2::lefiMacro_hasXSymmetry(obj) != 0,
+                y: si2::lefiMacro_hasYSymmetry(obj) != 0,
+                r90: si2::lefiMacro_has90Symmetry(obj) != 0,
+            };
+
+            // SITE
+            if si2::lefiMacro_hasSiteName(obj) != 0 {
+                let mut site = LefSite::default();
+                site.name = utils::const_c_char_ptr_to_string(si2::lefiMacro_siteName(obj));
+                makcro.sites.push(site);
+            }
+            for index in 0..si2::lefiMacro_numSitePattern(obj) {
+                match read_site_pattern(si2::lefiMacro_sitePattern(obj, index)) {
+                    Ok(pattern) => makcro.sites.push(pattern),
+                    Err(err) => {
+                        reader.error = Some(err);
+                        return 1;
+                    }
+                }
+            }
+   
+            // PIN
+            makcro.pins = reader.take_pins();
+            
+            // OBS
+            (makcro.obs, makcro.obs_vias) = reader.take_geometries();
+
+            // DENSITY
+            makcro.density = reader.take_density();
+
+            // TIMING
+            makcro.timings = reader.take_timings();
+
+            // PROPERTY
+            makcro.properties = read_properties!(obj, lefiMacro, numProperties, propNum, &reader.lef.property_definitions, LefPropertyObjectType::Macro);
+
+            reader.lef.macros.insert(makcro.name.clone(), makcro);   
+        }
+        0
+    }
+
+    pub unsafe extern "C" fn read_pin(_: si2::lefrCallbackType_e, obj: *mut si2::lefiPin, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            
+            let mut pin = LefMacroPin::default();
+            pin.name = utils::const_c_char_ptr_to_string(si2::lefiPin_name(obj));  
+
+            if si2::lefiPin_hasDirection(obj) != 0 {
+                let dir = utils::const_c_char_ptr_to_str(si2::lefiPin_direction(obj));
+                match LefPinDirection::from_str(dir) {
+                    Ok(dir) => pin.direction = Some(dir),
+                    Err(_) => {
+                        reader.error = Some(LefReadError::Msg(format!("unknown pin direction '{}'", dir)));
+                        return 1;
+                    }
+                }
+            }
+
+            if si2::lefiPin_hasUse(obj) != 0 {
+                let uuse =  utils::const_c_char_ptr_to_str(si2::lefiPin_use(obj));
+                match LefSignalUse::from_str(uuse) {
+                    Ok(uuse) => pin.signal_use = Some(uuse),
+                    Err(_) => {
+                        reader.error = Some(LefReadError::Msg(format!("unknown pin use '{}'", uuse)));
+                        return 1;
+                    }
+                }
+            }
+
+            if si2::lefiPin_hasShape(obj) != 0 {
+                let shape =  utils::const_c_char_ptr_to_str(si2::lefiPin_shape(obj));
+                let shape = LefPinShape::from_str(&shape).unwrap();
+                pin.shape_type = Some(shape);
+            }
+
+            if si2::lefiPin_hasMustjoin(obj) != 0 {
+                pin.must_join = Some(utils::const_c_char_ptr_to_string(si2::lefiPin_mustjoin(obj)));
+            }
+
+            // TAPERRULE
+            if si2::lefiPin_hasTaperRule(obj) != 0 {
+                pin.taper_rule = Some(utils::const_c_char_ptr_to_string(si2::lefiPin_taperRule(obj)));
+            }
+
+            // NETEXPR
+            if si2::lefiPin_hasNetExpr(obj) != 0 {
+                let net_expr = utils::const_c_char_ptr_to_str(si2::lefiPin_netExpr(obj));
+                match LefNetExpr::from_str(net_expr) {
+                    Ok(net_expr) => pin.net_expr = Some(net_expr),
+                    Err(_) => {
+                        reader.error = Some(LefReadError::Msg(format!("invalid NETEXPR '{}'", net_expr)));
+                        return 1;
+                    }
+                }
+            }
+
+            // SUPPLYSENSITIVITY / GROUNDSENSITIVITY
+            if si2::lefiPin_hasSupplySensitivity(obj) != 0 {
+                pin.supply_sensitivity = Some(utils::const_c_char_ptr_to_string(si2::lefiPin_supplySensitivity(obj)));
+            }
+            if si2::lefiPin_hasGroundSensitivity(obj) != 0 {
+                pin.ground_sensitivity = Some(utils::const_c_char_ptr_to_string(si2::lefiPin_groundSensitivity(obj)));
+            }
+
+            // Electrical data
+            let electrical = &mut pin.electrical;
+            pin_attr_opt!(electrical.capacitance, obj, Capacitance, capacitance);
+            pin_attr_opt!(electrical.resistance, obj, Resistance, resistance);
+            pin_attr_opt!(electrical.leakage, obj, Leakage, leakage);
+            pin_attr_opt!(electrical.power, obj, Power, power);
+            pin_attr_opt!(electrical.max_load, obj, Maxload, maxload);
+            pin_attr_opt!(electrical.max_delay, obj, Maxdelay, maxdelay);
+            pin_attr_opt!(electrical.pulldown_res, obj, Pulldownres, pulldownres);
+            pin_attr_opt!(electrical.tieoff_r, obj, Tieoffr, tieoffr);
+            pin_attr_opt!(electrical.v_hi, obj, VHI, VHI);
+            pin_attr_opt!(electrical.v_lo, obj, VLO, VLO);
+            pin_attr_opt!(electrical.rise_voltage_threshold, obj, RiseVoltage, riseVoltage);
+            pin_attr_opt!(electrical.fall_voltage_threshold, obj, FallVoltage, fallVoltage);
+            pin_attr_opt!(electrical.rise_threshold, obj, RiseThresh, riseThresh);
+            pin_attr_opt!(electrical.fall_threshold, obj, FallThresh, fallThresh);
+            pin_attr_opt!(electrical.rise_sat_cur, obj, RiseSatcur, riseSatcur);
+            pin_attr_opt!(electrical.fall_sat_cur, obj, FallSatcur, fallSatcur);
+            pin_attr_opt!(electrical.rise_slew_limit, obj, RiseSlewLimit, riseSlewLimit);
+            pin_attr_opt!(electrical.fall_slew_limit, obj, FallSlewLimit, fallSlewLimit);
+            if si2::lefiPin_hasCurrentSource(obj) != 0 {
+                electrical.current_source = Some(utils::const_c_char_ptr_to_string(si2::lefiPin_currentSource(obj)));
+            }
+            if si2::lefiPin_hasTables(obj) != 0 {
+                electrical.iv_tables = Some((
+                    utils::const_c_char_ptr_to_string(si2::lefiPin_tableLowName(obj)),
+                    utils::const_c_char_ptr_to_string(si2::lefiPin_tableHighName(obj)),
+                ));
+            }
+            if si2::lefiPin_hasInMargin(obj) != 0 {
+                electrical.input_noise_margin = Some((si2::lefiPin_inMarginHigh(obj), si2::lefiPin_inMarginLow(obj)));
+            }
+            if si2::lefiPin_hasOutMargin(obj) != 0 {
+                electrical.output_noise_margin = Some((si2::lefiPin_outMarginHigh(obj), si2::lefiPin_outMarginLow(obj)));
+            }
+            if si2::lefiPin_hasOutResistance(obj) != 0 {
+                electrical.output_resistance = Some((si2::lefiPin_outResistanceHigh(obj), si2::lefiPin_outResistanceLow(obj)));
+            }
+
+            // ANTENNA
+            pin.antenna.partial_metal_area = antenna_values!(obj, lefiPin, PartialMetalArea);
+            pin.antenna.partial_metal_side_area = antenna_values!(obj, lefiPin, PartialMetalSideArea);
+            pin.antenna.partial_cut_area = antenna_values!(obj, lefiPin, PartialCutArea);
+            pin.antenna.diff_area = antenna_values!(obj, lefiPin, DiffArea);
+            for index in 0..si2::lefiPin_numAntennaModel(obj) {
+                let model_obj = si2::lefiPin_antennaModel(obj, index);
+                let mut model = LefPinAntennaModel::default();
+                model.oxide = utils::opt_const_c_char_ptr_to_string(si2::lefiPinAntennaModel_antennaOxide(model_obj))
+                    .unwrap_or_else(|| "OXIDE1".into());
+                model.gate_area = antenna_values!(model_obj, lefiPinAntennaModel, GateArea);
+                model.max_area_car = antenna_values!(model_obj, lefiPinAntennaModel, MaxAreaCar);
+                model.max_side_area_car = antenna_values!(model_obj, lefiPinAntennaModel, MaxSideAreaCar);
+                model.max_cut_car = antenna_values!(model_obj, lefiPinAntennaModel, MaxCutCar);
+                pin.antenna.models.push(model);
+            }
+
+            // PORT
+            for index in 0..si2::lefiPin_numPorts(obj) {
+                let port = si2::lefiPin_port(obj, index);
+                match Self::read_port(port) {
+                    Ok(port) => pin.ports.push(port),
+                    Err(err) => {
+                        reader.error = Some(err);
+                        return 1;
+                    }
+                }
+            }
+
+            // PROPERTY
+            pin.properties = read_properties!(obj, lefiPin, numProperties, propNum, &reader.lef.property_definitions, LefPropertyObjectType::Pin);
+
+            reader.pins.push(pin);
+        }
+        0
+    }
+
+    pub unsafe extern "C" fn read_obs(_: si2::lefrCallbackType_e, obj: *mut si2::lefiObstruction, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            let obj = si2::lefiObstruction_geometries(obj);
+            match Self::read_geometries(obj) {
+                Ok(geometries) => reader.geometries = geometries,
+                Err(err) => {
+                    reader.error = Some(err);
+                    return 1;
+                }
+            }
+        }
+        0
+    }
+
+    pub unsafe extern "C" fn read_density(_: si2::lefrCallbackType_e, obj: *mut si2::lefiDensity, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            for index in 0..si2::lefiDensity_numLayer(obj) {
+                let mut layer = LefDensityLayer::default();
+                layer.layer_name = utils::mut_c_char_ptr_to_string(si2::lefiDensity_layerName(obj, index));
+                for rect_index in 0..si2::lefiDensity_numRects(obj, index) {
+                    let rect = si2::lefiDensity_getRect(obj, index, rect_index);
+                    layer.rects.push(LefDensityRect {
+                        rect: ((rect.xl, rect.yl), (rect.xh, rect.yh)),
+                        density: si2::lefiDensity_densityValue(obj, index, rect_index),
+                    });
+                }
+                reader.density.push(layer);
+            }
+        }
+        0
+    }
+
+    unsafe fn read_port(obj: *const si2::lefiGeometries) -> LefReadResult<LefPort> {
+        unsafe {
+            let mut port = LefPort::default();
+            for index in 0..si2::lefiGeometries_numItems(obj) {
+                if si2::lefiGeometries_itemType(obj, index) == si2::lefiGeomEnum_lefiGeomClassE {
+                    let class = utils::const_c_char_ptr_to_str(si2::lefiGeometries_getClass(obj, index));
+                    let class = LefPortClass::from_str(class)
+                        .map_err(|_| LefReadError::Msg(format!("unknown port class '{}'", class)))?;
+                    port.class = Some(class);
+                }
+            }
+            (port.geometries, port.vias) = Self::read_geometries(obj)?;
+            Ok(port)
+        }
+    }
+
+    /// Read the LAYER blocks and the VIA placements of a PORT or OBS.
+    unsafe fn read_geometries(obj: *const si2::lefiGeometries) -> LefReadResult<(Vec<LefLayerGeometries>, Vec<LefViaInstance>)> {
+        unsafe {
+            let mut all_geometries: Vec<LefLayerGeometries> = vec![];
+            let mut vias = vec![];
+            for index in 0..si2::lefiGeometries_numItems(obj) {
+                let item_type = si2::lefiGeometries_itemType(obj, index);
+
+                // CLASS is read by `read_port`, LAYER starts a new block, VIA does not need a LAYER
+                match item_type {
+                    si2::lefiGeomEnum_lefiGeomClassE => continue,
+                    si2::lefiGeomEnum_lefiGeomLayerE => {
+                        let mut geometries = LefLayerGeometries::default();
+                        geometries.layer_name = utils::const_c_char_ptr_to_string(si2::lefiGeometries_getLayer(obj, index));
+                        all_geometries.push(geometries);
+                        continue;
+                    }
+                    si2::lefiGeomEnum_lefiGeomViaE | si2::lefiGeomEnum_lefiGeomViaIterE => {
+                        let via = if item_type == si2::lefiGeomEnum_lefiGeomViaE {
+                            let via = &*si2::lefiGeometries_getVia(obj, index);
+                            LefViaInstance {
+                                via_name: utils::const_c_char_ptr_to_string(via.name),
+                                location: (via.x, via.y),
+                                masks: (utils::mask_number(via.topMaskNum), utils::mask_number(via.cutMaskNum), utils::mask_number(via.bottomMaskNum)),
+                                step_pattern: None,
+                            }
+                        } else {
+                            let via = &*si2::lefiGeometries_getViaIter(obj, index);
+                            LefViaInstance {
+                                via_name: utils::const_c_char_ptr_to_string(via.name),
+                                location: (via.x, via.y),
+                                masks: (utils::mask_number(via.topMaskNum), utils::mask_number(via.cutMaskNum), utils::mask_number(via.bottomMaskNum)),
+                                step_pattern: Some(step_pattern(via.xStart, via.yStart, via.xStep, via.yStep)),
+                            }
+                        };
+                        vias.push(via);
+                        continue;
+                    }
+                    _ => {}
+                }
+
+                // All other items belong to the current LAYER
+                let Some(geometries) = all_geometries.last_mut() else {
+                    return Err(LefReadError::Msg("geometry defined before any LAYER".into()));
+                };
+                
+                match item_type {
+                    si2::lefiGeomEnum_lefiGeomLayerExceptPgNetE => {
+                        geometries.except_pg_net = si2::lefiGeometries_hasLayerExceptPgNet(obj, index) != 0;
+                    }
+                    si2::lefiGeomEnum_lefiGeomLayerMinSpacingE => {
+                        let spacing = si2::lefiGeometries_getLayerMinSpacing(obj, index);
+                        geometries.spacing_or_designrule_width = Some(LefSpacingOrDesignRuleWidth::MinSpacing(spacing));
+                    }
+                    si2::lefiGeomEnum_lefiGeomLayerRuleWidthE => {
+                        let width = si2::lefiGeometries_getLayerRuleWidth(obj, index);
+                        geometries.spacing_or_designrule_width = Some(LefSpacingOrDesignRuleWidth::DesignRuleWidth(width));
+                    }
+                    si2::lefiGeomEnum_lefiGeomWidthE => {
+                        geometries.width = Some(si2::lefiGeometries_getWidth(obj, index));
+                    }
+                    si2::lefiGeomEnum_lefiGeomPathE => {
+                        let path = &*si2::lefiGeometries_getPath(obj, index);
+                        let points = read_points(path.numPoints, path.x, path.y);
+                        geometries.geometries.push(LefGeometry::Path(geometries.width, points, utils::mask_number(path.colorMask)));
+                    }
+                    si2::lefiGeomEnum_lefiGeomPathIterE => {
+                        let path = &*si2::lefiGeometries_getPathIter(obj, index);
+                        let points = read_points(path.numPoints, path.x, path.y);
+                        let pattern = step_pattern(path.xStart, path.yStart, path.xStep, path.yStep);
+                        geometries.geometries.push(LefGeometry::Iterate(Box::new(LefGeometry::Path(geometries.width, points, utils::mask_number(path.colorMask))), pattern));
+                    }
+                    si2::lefiGeomEnum_lefiGeomRectE => {
+                        let rect = si2::lefiGeometries_getRect(obj, index);
+                        let rect = &*rect;
+                        geometries.geometries.push(LefGeometry::Rect((rect.xl, rect.yl), (rect.xh, rect.yh), utils::mask_number(rect.colorMask)));
+                    }
+                    si2::lefiGeomEnum_lefiGeomRectIterE => {
+                        let rect = &*si2::lefiGeometries_getRectIter(obj, index);
+                        let pattern = step_pattern(rect.xStart, rect.yStart, rect.xStep, rect.yStep);
+                        geometries.geometries.push(LefGeometry::Iterate(Box::new(LefGeometry::Rect((rect.xl, rect.yl), (rect.xh, rect.yh), utils::mask_number(rect.colorMask))), pattern));
+                    }
+                    si2::lefiGeomEnum_lefiGeomPolygonE => {
+                        let polygon = si2::lefiGeometries_getPolygon(obj, index);
+                        let polygon = &*polygon;
+                        let points = read_points(polygon.numPoints, polygon.x, polygon.y);
+                        geometries.geometries.push(LefGeometry::Polygon(points, utils::mask_number(polygon.colorMask)));
+                    }
+                    si2::lefiGeomEnum_lefiGeomPolygonIterE => {
+                        let polygon = &*si2::lefiGeometries_getPolygonIter(obj, index);
+                        let points = read_points(polygon.numPoints, polygon.x, polygon.y);
+                        let pattern = step_pattern(polygon.xStart, polygon.yStart, polygon.xStep, polygon.yStep);
+                        geometries.geometries.push(LefGeometry::Iterate(Box::new(LefGeometry::Polygon(points, utils::mask_number(polygon.colorMask))), pattern));
+                    }
+                    _ => return Err(LefReadError::Msg(format!("unsupported geometry type {}", item_type))),
+                };
+            }
+            
+            Ok((all_geometries, vias))
+        }
+    }
+}
+
+unsafe fn read_points(num_points: c_int, x: *const f64, y: *const f64) -> Vec<(f64, f64)> {
+    unsafe {
+        (0..num_points as usize)
+            .map(|index| (*x.add(index), *y.add(index)))
+            .collect()
+    }
+}
+
+/// Si2 stores the DO and BY counts of an ITERATE in the start fields.
+fn step_pattern(x_start: f64, y_start: f64, x_step: f64, y_step: f64) -> LefStepPattern {
+    LefStepPattern { x_step, y_step, x_start, y_start }
+}
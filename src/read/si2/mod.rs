@@ -0,0 +1,390 @@
+mod utils;
+mod layer;
+mod via;
+mod site;
+mod makro;
+mod property;
+mod nondefault;
+mod electrical;
+mod timing;
+mod array;
+
+use std::str::FromStr;
+use std::sync::RwLock;
+use super::{LefReadError, LefReadResult};
+use std::path::Path;
+use crate::si2;
+use crate::LefCellLibrary;
+use crate::LefClearanceMeasure;
+use crate::LefDensityLayer;
+use crate::LefExtension;
+use crate::LefLayerGeometries;
+use crate::LefMacroPin;
+use crate::LefMaxViaStack;
+use crate::LefTechnology;
+use crate::LefTiming;
+use crate::LefViaInstance;
+use std::os::raw::{c_void, c_int, c_char};
+use std::sync::LazyLock;
+
+impl LefTechnology {
+    /// Load a technology LEF file with the Si2 parser.
+    pub fn load_file_si2<P: AsRef<Path>>(path: P) -> LefReadResult<Self> {
+        let reader = LefTechnologyReader::new();
+        unsafe { reader.load_file_inner(path.as_ref()) }
+    }
+}
+
+impl LefCellLibrary {
+    /// Load a cell library LEF file with the Si2 parser.
+    pub fn load_file_si2<P: AsRef<Path>>(path: P) -> LefReadResult<Self> {
+        let reader = LefCellLibraryReader::new();
+        unsafe { reader.load_file_inner(path.as_ref()) }
+    }
+}
+
+static ERROR_MESSAGE: LazyLock<RwLock<String>> = LazyLock::new(|| {
+    RwLock::new(String::from("hello"))
+});
+
+unsafe extern "C" fn log(msg: *const ::std::os::raw::c_char) {
+    let msg = unsafe { utils::const_c_char_ptr_to_string(msg) };
+    let mut locked = ERROR_MESSAGE.write().unwrap();
+    *locked = msg;  
+}
+
+//=====================================================================
+//                    Technology Reader
+//=====================================================================
+
+pub struct LefTechnologyReader {
+    lef: LefTechnology,
+    error: Option<LefReadError>,
+}
+
+impl LefTechnologyReader {
+    fn new() -> Self {
+        Self { lef: Default::default(), error: None }
+    }
+
+    unsafe fn load_file_inner(mut self, path: &Path) -> LefReadResult<LefTechnology> {
+        let path = path.to_str().unwrap();
+        
+        ERROR_MESSAGE.write().unwrap().clear();
+        unsafe { 
+            si2::lefrInit(); 
+            si2::lefrSetVersionCbk(Some(Self::read_version));
+            si2::lefrSetBusBitCharsCbk(Some(Self::read_busbitchars));
+            si2::lefrSetDividerCharCbk(Some(Self::read_dividerchar));
+            si2::lefrSetUnitsCbk(Some(Self::read_units));
+            si2::lefrSetManufacturingCbk(Some(Self::read_manufacturing_grid));
+            si2::lefrSetClearanceMeasureCbk(Some(Self::read_clearance_measure));
+            si2::lefrSetSiteCbk(Some(Self::read_site));
+            si2::lefrSetLayerCbk(Some(Self::read_layer));
+            si2::lefrSetViaCbk(Some(Self::read_via));
+            si2::lefrSetViaRuleCbk(Some(Self::read_viarule));
+            si2::lefrSetNonDefaultCbk(Some(Self::read_non_default_rule));
+            si2::lefrSetPropCbk(Some(Self::read_property_definition));
+            si2::lefrSetMaxStackViaCbk(Some(Self::read_max_via_stack));
+            si2::lefrSetUseMinSpacingCbk(Some(Self::read_use_min_spacing));
+            si2::lefrSetNoWireExtensionCbk(Some(Self::read_no_wire_extension));
+            si2::lefrSetMinFeatureCbk(Some(Self::read_min_feature));
+            si2::lefrSetFixedMaskCbk(Some(Self::read_fixed_mask));
+            si2::lefrSetIRDropCbk(Some(Self::read_ir_drop));
+            si2::lefrSetDielectricCbk(Some(Self::read_dielectric));
+            si2::lefrSetNoiseMarginCbk(Some(Self::read_noise_margin));
+            si2::lefrSetEdgeRateThreshold1Cbk(Some(Self::read_edge_rate_threshold1));
+            si2::lefrSetEdgeRateThreshold2Cbk(Some(Self::read_edge_rate_threshold2));
+            si2::lefrSetEdgeRateScaleFactorCbk(Some(Self::read_edge_rate_scale_factor));
+            si2::lefrSetNoiseTableCbk(Some(Self::read_noise_table));
+            si2::lefrSetCorrectionTableCbk(Some(Self::read_correction_table));
+            si2::lefrSetArrayCbk(Some(Self::read_array));
+            si2::lefrSetExtensionCbk(Some(Self::read_extension));
+            si2::lefrSetLogFunction(Some(log));
+
+            let self_ptr = &mut self as *mut Self as *mut c_void;
+
+            let (fp, fp_for_lefr) = utils::open_c_file(path, "r");
+            let ret = si2::lefrRead(fp_for_lefr, path.as_ptr() as *const std::os::raw::c_char, self_ptr);
+            if ret != 0 && self.error.is_none() {
+                self.error = Some(LefReadError::Si2(ERROR_MESSAGE.read().unwrap().clone()));
+                ERROR_MESSAGE.write().unwrap().clear();
+            }
+
+            si2::lefrReleaseNResetMemory();
+
+            libc::fclose(fp);
+        };
+
+        match self.error {
+            None => Ok(self.lef),
+            Some(err) => Err(err),
+        }
+    }
+
+    unsafe extern "C" fn read_version(_: si2::lefrCallbackType_e, version: f64, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.version = Some(version);
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_busbitchars(_: si2::lefrCallbackType_e, raw: *const c_char, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            // let busbitchars = utils::const_c_char_ptr_to_cstr(raw);
+            reader.lef.busbitchars = ((*raw) as u8 as char, *raw.add(1) as u8 as char);
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_dividerchar(_: si2::lefrCallbackType_e, raw: *const c_char, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            // let dividerchar = utils::const_c_char_ptr_to_cstr(raw);
+            reader.lef.dividerchar = (*raw) as u8 as char;
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_units(_: si2::lefrCallbackType_e, obj: *mut si2::lefiUnits, ud: *mut c_void) -> c_int {
+        unsafe  {
+            let reader = &mut *(ud as *mut Self);
+            if si2::lefiUnits_hasTime(obj) != 0 {
+                reader.lef.units.time_ns = si2::lefiUnits_time(obj);   
+            }
+            if si2::lefiUnits_hasCapacitance(obj) != 0 {
+                reader.lef.units.capacitance_pf = si2::lefiUnits_capacitance(obj);
+            }
+            if si2::lefiUnits_hasResistance(obj) != 0 {
+                reader.lef.units.resistance_ohms = si2::lefiUnits_resistance(obj);
+            }
+            if si2::lefiUnits_hasPower(obj) != 0 {
+                reader.lef.units.power_mw = si2::lefiUnits_power(obj);
+            }
+            if si2::lefiUnits_hasCurrent(obj) != 0 {
+                reader.lef.units.current_ma = si2::lefiUnits_current(obj);
+            }
+            if si2::lefiUnits_hasVoltage(obj) != 0 {
+                reader.lef.units.voltage_v = si2::lefiUnits_voltage(obj);
+            }
+            if si2::lefiUnits_hasDatabase(obj) != 0 {
+                reader.lef.units.database_microns = si2::lefiUnits_databaseNumber(obj);
+            }
+            if si2::lefiUnits_hasFrequency(obj) != 0 {
+                reader.lef.units.frequency_mega_hz = si2::lefiUnits_frequency(obj);
+            }
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_manufacturing_grid(_: si2::lefrCallbackType_e, number: f64, ud: *mut c_void) -> c_int {
+        unsafe  {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.manufacturing_grid = Some(number);
+        }
+        0
+    } 
+
+    unsafe extern "C" fn read_clearance_measure(_: si2::lefrCallbackType_e, string: *const c_char, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.clearance_measure = LefClearanceMeasure::from_str(&utils::const_c_char_ptr_to_str(string)).unwrap();
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_max_via_stack(_: si2::lefrCallbackType_e, obj: *mut si2::lefiMaxStackVia, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            let range = if si2::lefiMaxStackVia_hasMaxStackViaRange(obj) != 0 {
+                Some((
+                    utils::const_c_char_ptr_to_string(si2::lefiMaxStackVia_maxStackViaBottomLayer(obj)),
+                    utils::const_c_char_ptr_to_string(si2::lefiMaxStackVia_maxStackViaTopLayer(obj)),
+                ))
+            } else {
+                None
+            };
+            reader.lef.max_via_stack = Some(LefMaxViaStack {
+                max_stack: si2::lefiMaxStackVia_maxStackVia(obj) as u32,
+                range,
+            });
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_use_min_spacing(_: si2::lefrCallbackType_e, obj: *mut si2::lefiUseMinSpacing, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            let name = utils::const_c_char_ptr_to_str(si2::lefiUseMinSpacing_name(obj));
+            // USEMINSPACING PIN is obsolete, only OBS is kept
+            if name.eq_ignore_ascii_case("OBS") {
+                reader.lef.use_min_spacing_obs = Some(si2::lefiUseMinSpacing_value(obj) != 0);
+            }
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_no_wire_extension(_: si2::lefrCallbackType_e, string: *const c_char, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.no_wire_extension_at_pin = Some(utils::const_c_char_ptr_to_str(string) == "ON");
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_min_feature(_: si2::lefrCallbackType_e, obj: *mut si2::lefiMinFeature, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.min_feature = Some((si2::lefiMinFeature_one(obj), si2::lefiMinFeature_two(obj)));
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_fixed_mask(_: si2::lefrCallbackType_e, number: c_int, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.fixed_mask = number != 0;
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_extension(_: si2::lefrCallbackType_e, raw: *const c_char, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            match read_extension_text(raw) {
+                Ok(extension) => reader.lef.extensions.push(extension),
+                Err(err) => {
+                    reader.error = Some(err);
+                    return 1;
+                }
+            }
+        }
+        0
+    }
+}
+
+//=====================================================================
+//                    Cell Library Reader
+//=====================================================================
+
+pub struct LefCellLibraryReader {
+    lef: LefCellLibrary,
+    error: Option<LefReadError>,
+    pins: Vec<LefMacroPin>,
+    geometries: (Vec<LefLayerGeometries>, Vec<LefViaInstance>),
+    density: Vec<LefDensityLayer>,
+    timings: Vec<LefTiming>,
+}
+
+impl LefCellLibraryReader {
+    fn new() -> Self {
+        Self { lef: Default::default(), error: None, pins: vec![], geometries: Default::default(), density: vec![], timings: vec![] }
+    }
+
+    fn take_pins(&mut self) -> Vec<LefMacroPin> {
+        std::mem::take(&mut self.pins)
+    }
+
+    fn take_geometries(&mut self) -> (Vec<LefLayerGeometries>, Vec<LefViaInstance>) {
+        std::mem::take(&mut self.geometries)
+    }
+
+    fn take_density(&mut self) -> Vec<LefDensityLayer> {
+        std::mem::take(&mut self.density)
+    }
+
+    fn take_timings(&mut self) -> Vec<LefTiming> {
+        std::mem::take(&mut self.timings)
+    }
+
+    unsafe fn load_file_inner(mut self, path: &Path) -> LefReadResult<LefCellLibrary> {
+        let path = path.to_str().unwrap();
+        
+        ERROR_MESSAGE.write().unwrap().clear();
+        unsafe { 
+            si2::lefrInit(); 
+            si2::lefrSetVersionCbk(Some(Self::read_version));
+            si2::lefrSetBusBitCharsCbk(Some(Self::read_busbitchars));
+            si2::lefrSetDividerCharCbk(Some(Self::read_dividerchar));
+            si2::lefrSetMacroCbk(Some(Self::read_macro));
+            si2::lefrSetSiteCbk(Some(Self::read_site));
+            si2::lefrSetPinCbk(Some(Self::read_pin));
+            si2::lefrSetViaCbk(Some(Self::read_via));
+            si2::lefrSetObstructionCbk(Some(Self::read_obs));
+            si2::lefrSetDensityCbk(Some(Self::read_density));
+            si2::lefrSetTimingCbk(Some(Self::read_timing));
+            si2::lefrSetPropCbk(Some(Self::read_property_definition));
+            si2::lefrSetExtensionCbk(Some(Self::read_extension));
+            si2::lefrSetLogFunction(Some(log));
+
+            let self_ptr = &mut self as *mut Self as *mut c_void;
+
+            let (fp, fp_for_lefr) = utils::open_c_file(path, "r");
+            let ret = si2::lefrRead(fp_for_lefr, path.as_ptr() as *const std::os::raw::c_char, self_ptr);
+            if ret != 0 && self.error.is_none() {
+                self.error = Some(LefReadError::Si2(ERROR_MESSAGE.read().unwrap().clone()));
+                ERROR_MESSAGE.write().unwrap().clear();
+            }
+
+            si2::lefrReleaseNResetMemory();
+
+            libc::fclose(fp);
+        };
+
+        match self.error {
+            None => Ok(self.lef),
+            Some(err) => Err(err),
+        }
+    }
+
+    unsafe extern "C" fn read_version(_: si2::lefrCallbackType_e, version: f64, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            reader.lef.version = Some(version);
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_busbitchars(_: si2::lefrCallbackType_e, raw: *const c_char, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            // let busbitchars = utils::const_c_char_ptr_to_cstr(raw);
+            reader.lef.busbitchars = ((*raw) as u8 as char, *raw.add(1) as u8 as char);
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_dividerchar(_: si2::lefrCallbackType_e, raw: *const c_char, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            // let dividerchar = utils::const_c_char_ptr_to_cstr(raw);
+            reader.lef.dividerchar = (*raw) as u8 as char;
+        }
+        0
+    }
+
+    unsafe extern "C" fn read_extension(_: si2::lefrCallbackType_e, raw: *const c_char, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            match read_extension_text(raw) {
+                Ok(extension) => reader.lef.extensions.push(extension),
+                Err(err) => {
+                    reader.error = Some(err);
+                    return 1;
+                }
+            }
+        }
+        0
+    }
+}
+
+/// Si2 passes the text following BEGINEXT up to and including ENDEXT.
+unsafe fn read_extension_text(raw: *const c_char) -> LefReadResult<LefExtension> {
+    unsafe {
+        let text = utils::const_c_char_ptr_to_string(raw);
+        LefExtension::from_str(&text)
+            .map_err(|_| LefReadError::Msg(format!("invalid BEGINEXT block '{}'", text.trim())))
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{LefNonDefaultRule, LefNonDefaultRuleLayer, LefPropertyObjectType, LefVia};
+use super::LefTechnologyReader;
+use super::via::do_read_via;
+use super::property::read_properties;
+use crate::si2;
+use super::utils;
+use std::os::raw::{c_int, c_void};
+use paste::paste;
+
+macro_rules! nondefault_layer_attr_opt {
+    ($layer:ident, $si2_obj:ident, $index:ident, $field_name:ident, $attr_name:ident) => {
+        paste! {
+            if si2::[< lefiNonDefault_hasLayer $attr_name >]($si2_obj, $index) != 0 {
+                $layer.$field_name = Some(si2::[< lefiNonDefault_layer $attr_name >]($si2_obj, $index));
+            }
+        }
+    };
+}
+
+impl LefTechnologyReader {
+    pub unsafe extern "C" fn read_non_default_rule(_: si2::lefrCallbackType_e, obj: *mut si2::lefiNonDefault, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+
+            let mut rule = LefNonDefaultRule {
+                name: utils::const_c_char_ptr_to_string(si2::lefiNonDefault_name(obj)),
+                hard_spacing: si2::lefiNonDefault_hasHardspacing(obj) != 0,
+                ..Default::default()
+            };
+
+            // LAYER
+            for index in 0..si2::lefiNonDefault_numLayers(obj) {
+                let mut layer = LefNonDefaultRuleLayer {
+                    name: utils::const_c_char_ptr_to_string(si2::lefiNonDefault_layerName(obj, index)),
+                    ..Default::default()
+                };
+                nondefault_layer_attr_opt!(layer, obj, index, width, Width);
+                nondefault_layer_attr_opt!(layer, obj, index, diag_width, DiagWidth);
+                nondefault_layer_attr_opt!(layer, obj, index, spacing, Spacing);
+                nondefault_layer_attr_opt!(layer, obj, index, wire_extension, WireExtension);
+                nondefault_layer_attr_opt!(layer, obj, index, resistance, Resistance);
+                nondefault_layer_attr_opt!(layer, obj, index, capacitance, Capacitance);
+                nondefault_layer_attr_opt!(layer, obj, index, edge_capacitance, EdgeCap);
+                rule.layers.push(layer);
+            }
+
+            // VIA
+            for index in 0..si2::lefiNonDefault_numVias(obj) {
+                let via_obj = si2::lefiNonDefault_viaRule(obj, index) as *mut si2::lefiVia;
+                let via_name = utils::const_c_char_ptr_to_string(si2::lefiVia_name(via_obj));
+                let mut via = LefVia::default();
+                do_read_via(via_obj, &mut via, &reader.lef.property_definitions);
+                rule.vias.insert(via_name, via);
+            }
+
+            // USEVIA
+            for index in 0..si2::lefiNonDefault_numUseVia(obj) {
+                rule.use_vias.push(utils::const_c_char_ptr_to_string(si2::lefiNonDefault_viaName(obj, index)));
+            }
+
+            // USEVIARULE
+            for index in 0..si2::lefiNonDefault_numUseViaRule(obj) {
+                rule.use_via_rules.push(utils::const_c_char_ptr_to_string(si2::lefiNonDefault_viaRuleName(obj, index)));
+            }
+
+            // MINCUTS
+            for index in 0..si2::lefiNonDefault_numMinCuts(obj) {
+                let cut_layer = utils::const_c_char_ptr_to_string(si2::lefiNonDefault_cutLayerName(obj, index));
+                rule.min_cuts.push((cut_layer, si2::lefiNonDefault_numCuts(obj, index) as u32));
+            }
+
+            // PROPERTY
+            rule.properties = read_properties!(obj, lefiNonDefault, numProps, propNumber, &reader.lef.property_definitions, LefPropertyObjectType::NonDefaultRule);
+
+            reader.lef.non_default_rules.insert(rule.name.clone(), rule);
+        }
+        0
+    }
+}
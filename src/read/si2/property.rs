@@ -0,0 +1,97 @@
+use crate::{LefPropertyDefinition, LefPropertyObjectType, LefPropertyType, LefPropertyValue};
+use super::{LefCellLibraryReader, LefReadError, LefReadResult, LefTechnologyReader};
+use crate::si2;
+use super::utils;
+use std::{os::raw::{c_int, c_void}, str::FromStr};
+
+/// Read the PROPERTY statements of a Si2 object into a map, typed by the property definitions.
+macro_rules! read_properties {
+    ($si2_obj:ident, $si2_type:ident, $num_props:ident, $prop_number:ident, $definitions:expr, $object_type:expr) => {
+        paste::paste! {
+            {
+                let mut properties = std::collections::HashMap::new();
+                for index in 0..si2::[< $si2_type _ $num_props >]($si2_obj) {
+                    let name = utils::const_c_char_ptr_to_string(si2::[< $si2_type _propName >]($si2_obj, index));
+                    let number = if si2::[< $si2_type _propIsNumber >]($si2_obj, index) != 0 {
+                        Some(si2::[< $si2_type _ $prop_number >]($si2_obj, index))
+                    } else {
+                        None
+                    };
+                    let value = utils::const_c_char_ptr_to_string(si2::[< $si2_type _propValue >]($si2_obj, index));
+                    let value = crate::read::property::property_value($definitions, $object_type, &name, number, value);
+                    properties.insert(name, value);
+                }
+                properties
+            }
+        }
+    };
+}
+
+pub(crate) use read_properties;
+
+impl LefTechnologyReader {
+    pub unsafe extern "C" fn read_property_definition(_: si2::lefrCallbackType_e, obj: *mut si2::lefiProp, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            match do_read_property_definition(obj) {
+                Ok(definition) => reader.lef.property_definitions.push(definition),
+                Err(err) => {
+                    reader.error = Some(err);
+                    return 1;
+                }
+            }
+        }
+        0
+    }
+}
+
+impl LefCellLibraryReader {
+    pub unsafe extern "C" fn read_property_definition(_: si2::lefrCallbackType_e, obj: *mut si2::lefiProp, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            match do_read_property_definition(obj) {
+                Ok(definition) => reader.lef.property_definitions.push(definition),
+                Err(err) => {
+                    reader.error = Some(err);
+                    return 1;
+                }
+            }
+        }
+        0
+    }
+}
+
+unsafe fn do_read_property_definition(obj: *mut si2::lefiProp) -> LefReadResult<LefPropertyDefinition> {
+    unsafe {
+        let object_type = utils::const_c_char_ptr_to_str(si2::lefiProp_propType(obj));
+        let object_type = LefPropertyObjectType::from_str(&object_type.to_ascii_uppercase())
+            .map_err(|_| LefReadError::Msg(format!("unknown property object type '{}'", object_type)))?;
+        let name = utils::const_c_char_ptr_to_string(si2::lefiProp_propName(obj));
+
+        let property_type = match si2::lefiProp_dataType(obj) as u8 {
+            b'I' => LefPropertyType::Integer,
+            b'R' => LefPropertyType::Real,
+            _ => LefPropertyType::String,
+        };
+
+        let range = if si2::lefiProp_hasRange(obj) != 0 {
+            Some((si2::lefiProp_left(obj), si2::lefiProp_right(obj)))
+        } else {
+            None
+        };
+
+        let default = if si2::lefiProp_hasNumber(obj) != 0 {
+            let number = si2::lefiProp_number(obj);
+            match property_type {
+                LefPropertyType::Integer => Some(crate::read::property::integer_value(number)),
+                _ => Some(LefPropertyValue::Real(number)),
+            }
+        } else if si2::lefiProp_hasString(obj) != 0 {
+            Some(LefPropertyValue::String(utils::const_c_char_ptr_to_string(si2::lefiProp_string(obj))))
+        } else {
+            None
+        };
+
+        Ok(LefPropertyDefinition { object_type, name, property_type, range, default })
+    }
+}
@@ -0,0 +1,97 @@
+use crate::{LefOrient, LefSite, LefSiteClass, LefSiteDefinition, LefStepPattern, LefSymmetry};
+use super::{LefCellLibraryReader, LefReadError, LefReadResult, LefTechnologyReader};
+use crate::si2;
+use super::utils;
+use std::collections::HashMap;
+use std::{os::raw::{c_int, c_void}, str::FromStr};
+
+impl LefTechnologyReader {
+    pub unsafe extern "C" fn read_site(_: si2::lefrCallbackType_e, obj: *mut si2::lefiSite, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+
+            let mut site = crate::LefSiteDefinition::default();
+            if let Err(err) = do_read_site(obj, &mut site, Some(&reader.lef.sites)) {
+                reader.error = Some(err);
+                return 1;
+            }
+            reader.lef.sites.insert(site.name.clone(), site);
+        }
+        0
+    }
+}
+
+impl LefCellLibraryReader {
+    pub unsafe extern "C" fn read_site(_: si2::lefrCallbackType_e, obj: *mut si2::lefiSite, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+
+            let mut site = crate::LefSiteDefinition::default();
+            if let Err(err) = do_read_site(obj, &mut site, None) {
+                reader.error = Some(err);
+                return 1;
+            }
+            reader.lef.sites.insert(site.name.clone(), site);
+        }
+        0
+    }
+}
+
+/// Read a SITE definition. The sites of a ROWPATTERN are checked against `sites` if given,
+/// which is only the case in the technology reader.
+pub unsafe fn do_read_site(obj: *mut si2::lefiSite, site: &mut LefSiteDefinition, sites: Option<&HashMap<String, LefSiteDefinition>>) -> LefReadResult<()> {
+    unsafe {
+        site.name = utils::const_c_char_ptr_to_string(si2::lefiSite_name(obj));
+        if si2::lefiSite_hasSize(obj) != 0 {
+            site.size = (si2::lefiSite_sizeX(obj), si2::lefiSite_sizeY(obj));
+        }
+        if si2::lefiSite_hasClass(obj) != 0 {
+            let class = utils::const_c_char_ptr_to_str(si2::lefiSite_siteClass(obj));
+            site.class = LefSiteClass::from_str(class).unwrap();
+        }
+
+        let x = si2::lefiSite_hasXSymmetry(obj) != 0;
+        let y = si2::lefiSite_hasYSymmetry(obj) != 0;
+        let r90 = si2::lefiSite_has90Symmetry(obj) != 0;
+        site.symmetry = LefSymmetry { x, y, r90 };
+
+        // ROWPATTERN
+        if si2::lefiSite_hasRowPattern(obj) != 0 {
+            for index in 0..si2::lefiSite_numSites(obj) {
+                let name = utils::const_c_char_ptr_to_string(si2::lefiSite_siteName(obj, index));
+                if sites.is_some_and(|sites| !sites.contains_key(&name)) {
+                    return Err(LefReadError::Msg(format!("ROWPATTERN of site '{}' uses undefined site '{}'", site.name, name)));
+                }
+                let orient = utils::const_c_char_ptr_to_str(si2::lefiSite_siteOrientStr(obj, index));
+                let orient = LefOrient::from_str(orient)
+                    .map_err(|_| LefReadError::Msg(format!("invalid orientation '{}' in ROWPATTERN of site '{}'", orient, site.name)))?;
+                site.row_pattern.push((name, orient));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Read a site pattern of a MACRO or ARRAY.
+pub unsafe fn read_site_pattern(obj: *const si2::lefiSitePattern) -> LefReadResult<LefSite> {
+    unsafe {
+        let mut pattern = LefSite::default();
+        pattern.name = utils::const_c_char_ptr_to_string(si2::lefiSitePattern_name(obj));
+        pattern.origin = (si2::lefiSitePattern_x(obj), si2::lefiSitePattern_y(obj));
+        let orient = utils::const_c_char_ptr_to_str(si2::lefiSitePattern_orientStr(obj));
+        pattern.site_orient = LefOrient::from_str(orient)
+            .map_err(|_| LefReadError::Msg(format!("invalid orientation '{}' of site '{}'", orient, pattern.name)))?;
+
+        if si2::lefiSitePattern_hasStepPattern(obj) != 0 {
+            pattern.step_pattern = Some(LefStepPattern {
+                x_start: si2::lefiSitePattern_xStart(obj),
+                y_start: si2::lefiSitePattern_yStart(obj),
+                x_step: si2::lefiSitePattern_xStep(obj),
+                y_step: si2::lefiSitePattern_yStep(obj),
+            })
+        }
+
+        Ok(pattern)
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{LefSdfTrigger, LefTiming, LefTimingIntrinsic, LefTimingStable, LefTimingTable};
+use super::{LefCellLibraryReader, LefReadError, LefReadResult};
+use crate::si2;
+use super::utils;
+use std::{os::raw::{c_char, c_int, c_void}, str::FromStr};
+use paste::paste;
+
+/// Read a pair of values like RISERS or FALLT0.
+macro_rules! timing_pair {
+    ($si2_obj:ident, $attr_name:ident, $getter:ident) => {
+        paste! {
+            if si2::[< lefiTiming_has $attr_name >]($si2_obj) != 0 {
+                Some((si2::[< lefiTiming_ $getter One >]($si2_obj), si2::[< lefiTiming_ $getter Two >]($si2_obj)))
+            } else {
+                None
+            }
+        }
+    };
+}
+
+/// Read the RISE or FALL INTRINSIC statement together with its slew and VARIABLE values.
+macro_rules! timing_intrinsic {
+    ($si2_obj:ident, $attr_name:ident, $getter:ident) => {
+        paste! {
+            if si2::[< lefiTiming_has $attr_name Intrinsic >]($si2_obj) != 0 {
+                let slew = if si2::[< lefiTiming_has $attr_name Slew >]($si2_obj) != 0 {
+                    Some((
+                        si2::[< lefiTiming_ $getter SlewOne >]($si2_obj),
+                        si2::[< lefiTiming_ $getter SlewTwo >]($si2_obj),
+                        si2::[< lefiTiming_ $getter SlewThree >]($si2_obj),
+                        si2::[< lefiTiming_ $getter SlewFour >]($si2_obj),
+                    ))
+                } else {
+                    None
+                };
+                let slew2 = if si2::[< lefiTiming_has $attr_name Slew2 >]($si2_obj) != 0 {
+                    Some((
+                        si2::[< lefiTiming_ $getter SlewFive >]($si2_obj),
+                        si2::[< lefiTiming_ $getter SlewSix >]($si2_obj),
+                        si2::[< lefiTiming_ $getter SlewSeven >]($si2_obj),
+                    ))
+                } else {
+                    None
+                };
+                Some(LefTimingIntrinsic {
+                    intrinsic: (si2::[< lefiTiming_ $getter IntrinsicOne >]($si2_obj), si2::[< lefiTiming_ $getter IntrinsicTwo >]($si2_obj)),
+                    slew,
+                    slew2,
+                    variable: (si2::[< lefiTiming_ $getter IntrinsicThree >]($si2_obj), si2::[< lefiTiming_ $getter IntrinsicFour >]($si2_obj)),
+                })
+            } else {
+                None
+            }
+        }
+    };
+}
+
+impl LefCellLibraryReader {
+    pub unsafe extern "C" fn read_timing(_: si2::lefrCallbackType_e, obj: *mut si2::lefiTiming, ud: *mut c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+            match do_read_timing(obj) {
+                Ok(timing) => reader.timings.push(timing),
+                Err(err) => {
+                    reader.error = Some(err);
+                    return 1;
+                }
+            }
+        }
+        0
+    }
+}
+
+unsafe fn do_read_timing(obj: *mut si2::lefiTiming) -> LefReadResult<LefTiming> {
+    unsafe {
+        let mut timing = LefTiming::default();
+
+        // FROMPIN / TOPIN
+        for index in 0..si2::lefiTiming_numFromPins(obj) {
+            timing.from_pins.push(utils::const_c_char_ptr_to_string(si2::lefiTiming_fromPin(obj, index)));
+        }
+        for index in 0..si2::lefiTiming_numToPins(obj) {
+            timing.to_pins.push(utils::const_c_char_ptr_to_string(si2::lefiTiming_toPin(obj, index)));
+        }
+
+        // INTRINSIC
+        timing.rise_intrinsic = timing_intrinsic!(obj, Rise, rise);
+        timing.fall_intrinsic = timing_intrinsic!(obj, Fall, fall);
+
+        timing.rise_rs = timing_pair!(obj, RiseRS, riseRS);
+        timing.fall_rs = timing_pair!(obj, FallRS, fallRS);
+        timing.rise_cs = timing_pair!(obj, RiseCS, riseCS);
+        timing.fall_cs = timing_pair!(obj, FallCS, fallCS);
+        timing.rise_satt1 = timing_pair!(obj, RiseAtt1, riseAtt1);
+        timing.fall_satt1 = timing_pair!(obj, FallAtt1, fallAtt1);
+        timing.rise_t0 = timing_pair!(obj, RiseTo, riseTo);
+        timing.fall_t0 = timing_pair!(obj, FallTo, fallTo);
+
+        // UNATENESS
+        if si2::lefiTiming_hasUnateness(obj) != 0 {
+            timing.unateness = Some(timing_keyword(si2::lefiTiming_unateness(obj))?);
+        }
+
+        // STABLE
+        if si2::lefiTiming_hasStableTiming(obj) != 0 {
+            timing.stable = Some(LefTimingStable {
+                setup: si2::lefiTiming_stableSetup(obj),
+                hold: si2::lefiTiming_stableHold(obj),
+                rise_fall: timing_keyword(si2::lefiTiming_stableRiseFall(obj))?,
+            });
+        }
+
+        // DELAY / TRANSITIONTIME
+        if si2::lefiTiming_hasDelay(obj) != 0 {
+            timing.delay = Some(LefTimingTable {
+                rise_fall: timing_keyword(si2::lefiTiming_delayRiseOrFall(obj))?,
+                unateness: timing_keyword(si2::lefiTiming_delayUnateness(obj))?,
+                dimension: (si2::lefiTiming_delayTableOne(obj), si2::lefiTiming_delayTableTwo(obj), si2::lefiTiming_delayTableThree(obj)),
+            });
+        }
+        if si2::lefiTiming_hasTransition(obj) != 0 {
+            timing.transition = Some(LefTimingTable {
+                rise_fall: timing_keyword(si2::lefiTiming_transitionRiseOrFall(obj))?,
+                unateness: timing_keyword(si2::lefiTiming_transitionUnateness(obj))?,
+                dimension: (si2::lefiTiming_transitionTableOne(obj), si2::lefiTiming_transitionTableTwo(obj), si2::lefiTiming_transitionTableThree(obj)),
+            });
+        }
+
+        // TABLEAXIS / TABLEENTRIES
+        let axis = si2::lefiTiming_axisNumbers(obj);
+        for index in 0..si2::lefiTiming_numOfAxisNumbers(obj) as usize {
+            timing.table_axis.push(*axis.add(index));
+        }
+        for index in 0..si2::lefiTiming_numOfTableEntries(obj) {
+            let (mut one, mut two, mut three) = (0.0, 0.0, 0.0);
+            si2::lefiTiming_tableEntry(obj, index, &mut one, &mut two, &mut three);
+            timing.table_entries.push((one, two, three));
+        }
+
+        // SDF triggers
+        let dimension = (si2::lefiTiming_SDFtriggerOne(obj), si2::lefiTiming_SDFtriggerTwo(obj), si2::lefiTiming_SDFtriggerThree(obj));
+        if si2::lefiTiming_hasSDFtwoPinTrigger(obj) != 0 {
+            timing.sdf_trigger = Some(LefSdfTrigger {
+                trigger_type: utils::const_c_char_ptr_to_string(si2::lefiTiming_SDFtwoPinTriggerType(obj)),
+                edges: Some((
+                    utils::const_c_char_ptr_to_string(si2::lefiTiming_SDFfromTrigger(obj)),
+                    utils::const_c_char_ptr_to_string(si2::lefiTiming_SDFtoTrigger(obj)),
+                )),
+                dimension,
+            });
+        } else if si2::lefiTiming_hasSDFonePinTrigger(obj) != 0 {
+            timing.sdf_trigger = Some(LefSdfTrigger {
+                trigger_type: utils::const_c_char_ptr_to_string(si2::lefiTiming_SDFonePinTriggerType(obj)),
+                edges: None,
+                dimension,
+            });
+        }
+        if si2::lefiTiming_hasSDFcondStart(obj) != 0 {
+            timing.sdf_cond_start = Some(utils::const_c_char_ptr_to_string(si2::lefiTiming_SDFcondStart(obj)));
+        }
+        if si2::lefiTiming_hasSDFcondEnd(obj) != 0 {
+            timing.sdf_cond_end = Some(utils::const_c_char_ptr_to_string(si2::lefiTiming_SDFcondEnd(obj)));
+        }
+        if si2::lefiTiming_hasSDFcond(obj) != 0 {
+            timing.sdf_cond = Some(utils::const_c_char_ptr_to_string(si2::lefiTiming_SDFcond(obj)));
+        }
+
+        Ok(timing)
+    }
+}
+
+/// Parse a RISE/FALL or unateness keyword of a TIMING statement.
+unsafe fn timing_keyword<T: FromStr>(raw: *const c_char) -> LefReadResult<T> {
+    unsafe {
+        let keyword = utils::const_c_char_ptr_to_str(raw);
+        T::from_str(keyword).map_err(|_| LefReadError::Msg(format!("invalid TIMING keyword '{}'", keyword)))
+    }
+}
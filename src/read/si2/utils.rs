@@ -22,6 +22,14 @@ pub unsafe fn const_c_char_ptr_to_string(raw: *const ::std::os::raw::c_char) ->
     unsafe { CStr::from_ptr(raw).to_string_lossy().into_owned() }
 }
 
+pub unsafe fn opt_const_c_char_ptr_to_string(raw: *const ::std::os::raw::c_char) -> Option<String> {
+    if raw.is_null() {
+        None
+    } else {
+        unsafe { Some(const_c_char_ptr_to_string(raw)) }
+    }
+}
+
 pub unsafe fn mut_c_char_ptr_to_string(raw: *mut ::std::os::raw::c_char) -> String {
     unsafe { CStr::from_ptr(raw).to_string_lossy().into_owned() }
 }
@@ -40,4 +48,9 @@ pub unsafe fn const_c_char_ptr_to_cstr(raw: *const ::std::os::raw::c_char) -> &'
 
 pub unsafe fn mut_c_char_ptr_to_cstr(raw: *mut ::std::os::raw::c_char) -> &'static CStr {
     unsafe { CStr::from_ptr(raw) }
-}
\ No newline at end of file
+}
+
+/// Si2 reports a missing MASK as `0`.
+pub fn mask_number(mask: ::std::os::raw::c_int) -> Option<u32> {
+    if mask > 0 { Some(mask as u32) } else { None }
+}
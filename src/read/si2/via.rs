@@ -0,0 +1,244 @@
+use crate::{LefPropertyDefinition, LefPropertyObjectType, LefRoutingDirection, LefVia, LefViaFixedRule, LefViaGenerateRule, LefViaLayer, LefViaRule, LefViaRuleLayer, LefViaRuleParams, LefViaShape};
+use super::{LefCellLibraryReader, LefReadError, LefReadResult, LefTechnologyReader};
+use crate::si2;
+use super::utils;
+use super::property::read_properties;
+use std::os::raw::c_int;
+
+impl LefTechnologyReader {
+    pub unsafe extern "C" fn read_via(_: si2::lefrCallbackType_e, obj: *mut si2::lefiVia, ud: *mut ::std::os::raw::c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+
+            let via_name = utils::const_c_char_ptr_to_string(si2::lefiVia_name(obj));
+            let mut via = LefVia::default();
+            do_read_via(obj, &mut via, &reader.lef.property_definitions);
+            reader.lef.vias.insert(via_name, via);
+        }
+        
+        0
+    }
+
+    pub unsafe extern "C" fn read_viarule(_: si2::lefrCallbackType_e, obj: *mut si2::lefiViaRule, ud: *mut ::std::os::raw::c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+
+            let rule_name = utils::const_c_char_ptr_to_string(si2::lefiViaRule_name(obj));
+            let via_rule = if si2::lefiViaRule_hasGenerate(obj) != 0 {
+                Self::read_viarule_generate(obj, rule_name.clone())
+            } else {
+                Self::read_viarule_fixed(obj, rule_name.clone())
+            };
+
+            match via_rule {
+                Ok(via_rule) => { reader.lef.via_rules.insert(rule_name, via_rule); }
+                Err(err) => {
+                    reader.error = Some(err);
+                    return 1;
+                }
+            }
+        }
+        
+        0
+    }
+
+    unsafe fn read_viarule_generate(obj: *mut si2::lefiViaRule, rule_name: String) -> LefReadResult<LefViaRule> {
+        unsafe {
+            let mut via_rule = LefViaGenerateRule {
+                is_default: si2::lefiViaRule_hasDefault(obj) != 0,
+                rule_name,
+                ..Default::default()
+            };
+
+            if si2::lefiViaRule_numLayers(obj) != 3 {
+                return Err(LefReadError::Msg(format!("VIARULE GENERATE '{}' requires three layers", via_rule.rule_name)));
+            }
+
+            // The cut layer is the one with RECT/SPACING, the routing layers keep their order in the file
+            let layers: Vec<_> = (0..3).map(|index| si2::lefiViaRule_layer(obj, index)).collect();
+            let Some(cut_index) = layers.iter().position(|&layer| {
+                si2::lefiViaRuleLayer_hasRect(layer) != 0 || si2::lefiViaRuleLayer_hasSpacing(layer) != 0
+            }) else {
+                return Err(LefReadError::Msg(format!("VIARULE GENERATE '{}' has no cut layer", via_rule.rule_name)));
+            };
+            let cut = layers[cut_index];
+            let mut routing = layers.iter().enumerate()
+                .filter(|(index, _)| *index != cut_index)
+                .map(|(_, &layer)| layer);
+            let bottom = routing.next().unwrap();
+            let top = routing.next().unwrap();
+
+            // LAYERS
+            let bottom_name = utils::const_c_char_ptr_to_string(si2::lefiViaRuleLayer_name(bottom));
+            let cut_name = utils::const_c_char_ptr_to_string(si2::lefiViaRuleLayer_name(cut));
+            let top_name = utils::const_c_char_ptr_to_string(si2::lefiViaRuleLayer_name(top));
+            via_rule.layers = (bottom_name, cut_name, top_name);
+
+            // ENCLOSURE
+            if si2::lefiViaRuleLayer_hasEnclosure(bottom) != 0 {
+                via_rule.enclosure.0 = (si2::lefiViaRuleLayer_enclosureOverhang1(bottom), si2::lefiViaRuleLayer_enclosureOverhang2(bottom));
+            }
+            if si2::lefiViaRuleLayer_hasEnclosure(top) != 0 {
+                via_rule.enclosure.1 = (si2::lefiViaRuleLayer_enclosureOverhang1(top), si2::lefiViaRuleLayer_enclosureOverhang2(top));
+            }
+
+            // WIDTH
+            if si2::lefiViaRuleLayer_hasWidth(bottom) != 0 {
+                via_rule.width.0 = (si2::lefiViaRuleLayer_widthMin(bottom), si2::lefiViaRuleLayer_widthMax(bottom));
+            }
+            if si2::lefiViaRuleLayer_hasWidth(top) != 0 {
+                via_rule.width.1 = (si2::lefiViaRuleLayer_widthMin(top), si2::lefiViaRuleLayer_widthMax(top));
+            }
+
+            // RECT
+            if si2::lefiViaRuleLayer_hasRect(cut) != 0 {
+                via_rule.rect.0 = (si2::lefiViaRuleLayer_xl(cut), si2::lefiViaRuleLayer_yl(cut));
+                via_rule.rect.1 = (si2::lefiViaRuleLayer_xh(cut), si2::lefiViaRuleLayer_yh(cut));
+            }
+            
+            // SPACING
+            if si2::lefiViaRuleLayer_hasSpacing(cut) != 0 {
+                via_rule.spacing.0 = si2::lefiViaRuleLayer_spacingStepX(cut);
+                via_rule.spacing.1 = si2::lefiViaRuleLayer_spacingStepY(cut);
+            }
+
+            Ok(LefViaRule::Generate(via_rule))
+        }
+    }
+
+    unsafe fn read_viarule_fixed(obj: *mut si2::lefiViaRule, rule_name: String) -> LefReadResult<LefViaRule> {
+        unsafe {
+            let mut via_rule = LefViaFixedRule {
+                rule_name,
+                ..Default::default()
+            };
+
+            if si2::lefiViaRule_numLayers(obj) != 2 {
+                return Err(LefReadError::Msg(format!("VIARULE '{}' requires two layers", via_rule.rule_name)));
+            }
+
+            // LAYERS
+            via_rule.layers = (
+                read_viarule_layer(si2::lefiViaRule_layer(obj, 0)),
+                read_viarule_layer(si2::lefiViaRule_layer(obj, 1)),
+            );
+
+            // VIA
+            for index in 0..si2::lefiViaRule_numVias(obj) {
+                via_rule.vias.push(utils::const_c_char_ptr_to_string(si2::lefiViaRule_viaName(obj, index)));
+            }
+
+            Ok(LefViaRule::Fixed(via_rule))
+        }
+    }
+}
+
+unsafe fn read_viarule_layer(obj: *const si2::lefiViaRuleLayer) -> LefViaRuleLayer {
+    unsafe {
+        let mut layer = LefViaRuleLayer {
+            name: utils::const_c_char_ptr_to_string(si2::lefiViaRuleLayer_name(obj)),
+            ..Default::default()
+        };
+
+        // DIRECTION
+        if si2::lefiViaRuleLayer_isHorizontal(obj) != 0 {
+            layer.direction = Some(LefRoutingDirection::Horizontal);
+        } else if si2::lefiViaRuleLayer_isVertical(obj) != 0 {
+            layer.direction = Some(LefRoutingDirection::Vertical);
+        }
+
+        // WIDTH
+        if si2::lefiViaRuleLayer_hasWidth(obj) != 0 {
+            layer.width = Some((si2::lefiViaRuleLayer_widthMin(obj), si2::lefiViaRuleLayer_widthMax(obj)));
+        }
+
+        layer
+    }
+}
+
+impl LefCellLibraryReader {
+    pub unsafe extern "C" fn read_via(_: si2::lefrCallbackType_e, obj: *mut si2::lefiVia, ud: *mut ::std::os::raw::c_void) -> c_int {
+        unsafe {
+            let reader = &mut *(ud as *mut Self);
+
+            let via_name = utils::const_c_char_ptr_to_string(si2::lefiVia_name(obj));
+            let mut via = LefVia::default();
+            do_read_via(obj, &mut via, &reader.lef.property_definitions);
+            reader.lef.vias.insert(via_name, via);
+        }
+        
+        0
+    }
+}
+
+pub unsafe fn do_read_via(obj: *mut si2::lefiVia, via: &mut LefVia, definitions: &[LefPropertyDefinition]) {
+    unsafe {
+        via.is_default = si2::lefiVia_hasDefault(obj) != 0;
+        
+        if si2::lefiVia_hasResistance(obj) != 0 {
+            via.resistance = Some(si2::lefiVia_resistance(obj));
+        }
+
+        // VIARULE
+        if si2::lefiVia_hasViaRule(obj) != 0 {
+            let mut params = LefViaRuleParams {
+                rule_name: utils::const_c_char_ptr_to_string(si2::lefiVia_viaRuleName(obj)),
+                cut_size: (si2::lefiVia_xCutSize(obj), si2::lefiVia_yCutSize(obj)),
+                layers: (
+                    utils::const_c_char_ptr_to_string(si2::lefiVia_botMetalLayer(obj)),
+                    utils::const_c_char_ptr_to_string(si2::lefiVia_cutLayer(obj)),
+                    utils::const_c_char_ptr_to_string(si2::lefiVia_topMetalLayer(obj)),
+                ),
+                cut_spacing: (si2::lefiVia_xCutSpacing(obj), si2::lefiVia_yCutSpacing(obj)),
+                enclosure: (
+                    (si2::lefiVia_xBotEnc(obj), si2::lefiVia_yBotEnc(obj)),
+                    (si2::lefiVia_xTopEnc(obj), si2::lefiVia_yTopEnc(obj)),
+                ),
+                ..Default::default()
+            };
+            if si2::lefiVia_hasRowCol(obj) != 0 {
+                params.row_col = (si2::lefiVia_numCutRows(obj) as u32, si2::lefiVia_numCutCols(obj) as u32);
+            }
+            if si2::lefiVia_hasOrigin(obj) != 0 {
+                params.origin = (si2::lefiVia_xOffset(obj), si2::lefiVia_yOffset(obj));
+            }
+            if si2::lefiVia_hasOffset(obj) != 0 {
+                params.offset = (
+                    (si2::lefiVia_xBotOffset(obj), si2::lefiVia_yBotOffset(obj)),
+                    (si2::lefiVia_xTopOffset(obj), si2::lefiVia_yTopOffset(obj)),
+                );
+            }
+            if si2::lefiVia_hasCutPattern(obj) != 0 {
+                params.pattern = Some(utils::const_c_char_ptr_to_string(si2::lefiVia_cutPattern(obj)));
+            }
+            via.via_rule = Some(params);
+        }
+
+        for l in 0..si2::lefiVia_numLayers(obj) {
+            let mut layer = LefViaLayer {
+                name: utils::const_c_char_ptr_to_string(si2::lefiVia_layerName(obj, l)),
+                ..Default::default()
+            };
+            for r in 0..si2::lefiVia_numRects(obj, l) {
+                let xl = si2::lefiVia_xl(obj, l, r);
+                let yl = si2::lefiVia_yl(obj, l, r);
+                let xh = si2::lefiVia_xh(obj, l, r);
+                let yh = si2::lefiVia_yh(obj, l, r);
+                let mask = utils::mask_number(si2::lefiVia_rectColorMask(obj, l, r));
+                layer.shapes.push(LefViaShape::Rect((xl, yl), (xh, yh), mask));
+            }
+            for p in 0..si2::lefiVia_numPolygons(obj, l) {
+                let poly = si2::lefiVia_getPolygon(obj, l, p);
+                let points = (0..poly.numPoints as usize)
+                    .map(|i| (*poly.x.add(i), *poly.y.add(i)))
+                    .collect();
+                let mask = utils::mask_number(si2::lefiVia_polyColorMask(obj, l, p));
+                layer.shapes.push(LefViaShape::Polygon(points, mask));
+            }
+            via.geometry.push(layer);
+        }
+
+        // PROPERTY
+        via.properties = read_properties!(obj, lefiVia, numProperties, propNumber, definitions, LefPropertyObjectType::Via);
+    }
+}
@@ -1837,6 +1837,285 @@ unsafe extern "C" {
 unsafe extern "C" {
     pub fn lefiMacro_print(obj: *const lefiMacro, f: *mut FILE);
 }
+unsafe extern "C" {
+    pub fn lefiTiming_numFromPins(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fromPin(obj: *const lefiTiming, index: ::std::os::raw::c_int) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_numToPins(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_toPin(obj: *const lefiTiming, index: ::std::os::raw::c_int) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasTransition(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasDelay(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasRiseSlew(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasRiseSlew2(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasFallSlew(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasFallSlew2(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasRiseIntrinsic(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasFallIntrinsic(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_numOfAxisNumbers(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_numOfTableEntries(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasRiseRS(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasRiseCS(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasFallRS(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasFallCS(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasUnateness(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasRiseAtt1(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasFallAtt1(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasFallTo(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasRiseTo(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasStableTiming(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasSDFonePinTrigger(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasSDFtwoPinTrigger(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasSDFcondStart(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasSDFcondEnd(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_hasSDFcond(obj: *const lefiTiming) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_axisNumbers(obj: *const lefiTiming) -> *mut f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_tableEntry(obj: *const lefiTiming, num: ::std::os::raw::c_int, one: *mut f64, two: *mut f64, three: *mut f64);
+}
+unsafe extern "C" {
+    pub fn lefiTiming_delayRiseOrFall(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_delayUnateness(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_transitionRiseOrFall(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_transitionUnateness(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_unateness(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_stableRiseFall(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_SDFonePinTriggerType(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_SDFtwoPinTriggerType(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_SDFfromTrigger(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_SDFtoTrigger(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_SDFcondStart(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_SDFcondEnd(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_SDFcond(obj: *const lefiTiming) -> *const ::std::os::raw::c_char;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_delayTableOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_delayTableTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_delayTableThree(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_transitionTableOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_transitionTableTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_transitionTableThree(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_SDFtriggerOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_SDFtriggerTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_SDFtriggerThree(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallIntrinsicOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallIntrinsicTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallIntrinsicThree(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallIntrinsicFour(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseIntrinsicOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseIntrinsicTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseIntrinsicThree(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseIntrinsicFour(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallSlewOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallSlewTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallSlewThree(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallSlewFour(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallSlewFive(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallSlewSix(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallSlewSeven(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseSlewOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseSlewTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseSlewThree(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseSlewFour(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseSlewFive(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseSlewSix(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseSlewSeven(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseRSOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseRSTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseCSOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseCSTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallRSOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallRSTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallCSOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallCSTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseAtt1One(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseAtt1Two(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallAtt1One(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallAtt1Two(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallToOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_fallToTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseToOne(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_riseToTwo(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_stableSetup(obj: *const lefiTiming) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiTiming_stableHold(obj: *const lefiTiming) -> f64;
+}
 unsafe extern "C" {
     pub fn lefiUnits_hasDatabase(obj: *const lefiUnits) -> ::std::os::raw::c_int;
 }
@@ -2463,6 +2742,88 @@ unsafe extern "C" {
         index: ::std::os::raw::c_int,
     ) -> *const lefiNoiseVictim;
 }
+unsafe extern "C" {
+    pub fn lefiNoiseEdge_edge(obj: *const lefiNoiseEdge) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiNoiseEdge_numResistances(obj: *const lefiNoiseEdge) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiNoiseEdge_resistance(
+        obj: *const lefiNoiseEdge,
+        index: ::std::os::raw::c_int,
+    ) -> *const lefiNoiseResistance;
+}
+unsafe extern "C" {
+    pub fn lefiNoiseTable_num(obj: *const lefiNoiseTable) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiNoiseTable_numEdges(obj: *const lefiNoiseTable) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiNoiseTable_edge(
+        obj: *const lefiNoiseTable,
+        index: ::std::os::raw::c_int,
+    ) -> *const lefiNoiseEdge;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionVictim_length(obj: *const lefiCorrectionVictim) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionVictim_numCorrections(obj: *const lefiCorrectionVictim)
+        -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionVictim_correction(
+        obj: *const lefiCorrectionVictim,
+        index: ::std::os::raw::c_int,
+    ) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionResistance_numNums(obj: *const lefiCorrectionResistance)
+        -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionResistance_num(
+        obj: *const lefiCorrectionResistance,
+        index: ::std::os::raw::c_int,
+    ) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionResistance_numVictims(obj: *const lefiCorrectionResistance)
+        -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionResistance_victim(
+        obj: *const lefiCorrectionResistance,
+        index: ::std::os::raw::c_int,
+    ) -> *const lefiCorrectionVictim;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionEdge_edge(obj: *const lefiCorrectionEdge) -> f64;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionEdge_numResistances(obj: *const lefiCorrectionEdge)
+        -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionEdge_resistance(
+        obj: *const lefiCorrectionEdge,
+        index: ::std::os::raw::c_int,
+    ) -> *const lefiCorrectionResistance;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionTable_num(obj: *const lefiCorrectionTable) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionTable_numEdges(obj: *const lefiCorrectionTable) -> ::std::os::raw::c_int;
+}
+unsafe extern "C" {
+    pub fn lefiCorrectionTable_edge(
+        obj: *const lefiCorrectionTable,
+        index: ::std::os::raw::c_int,
+    ) -> *const lefiCorrectionEdge;
+}
 unsafe extern "C" {
     pub fn lefiSetDebug(num: ::std::os::raw::c_int, value: ::std::os::raw::c_int);
 }
@@ -0,0 +1,48 @@
+//! Compare the native parser with the Si2 parser on the bench files and on the fixtures in
+//! `tests/data`, which cover the constructs the bench files do not use. Each fixture is read
+//! both as a technology and as a cell library.
+//!
+//! Needs both backends, so it only runs with the default `si2` feature plus `native-parser`:
+//!
+//! ```sh
+//! cargo test --features native-parser --test conformance
+//! ```
+#![cfg(all(feature = "si2", feature = "native-parser"))]
+
+use reda_lef::{LefCellLibrary, LefTechnology};
+
+const TECHNOLOGIES: [&str; 3] = [
+    "./bench/NangateOpenCellLibrary.tech.lef",
+    "./tests/data/constructs.lef",
+    "./tests/data/legacy.lef",
+];
+
+const CELL_LIBRARIES: [&str; 5] = [
+    "./bench/NangateOpenCellLibrary.macro.lef",
+    "./bench/NangateOpenCellLibrary.macro.mod.lef",
+    "./bench/NangateOpenCellLibrary.macro.rect.lef",
+    "./tests/data/constructs.lef",
+    "./tests/data/legacy.lef",
+];
+
+#[test]
+fn technology_matches_si2() {
+    for path in TECHNOLOGIES {
+        let si2 = LefTechnology::load_file_si2(path).unwrap();
+        let native = LefTechnology::load_file_native(path).unwrap();
+        assert_eq!(native, si2, "{}", path);
+    }
+}
+
+#[test]
+fn cell_libraries_match_si2() {
+    for path in CELL_LIBRARIES {
+        let si2 = LefCellLibrary::load_file_si2(path).unwrap();
+        let native = LefCellLibrary::load_file_native(path).unwrap();
+        assert_eq!(native.macros.len(), si2.macros.len(), "{}", path);
+        for (name, makcro) in si2.macros.iter() {
+            assert_eq!(native.macros.get(name), Some(makcro), "{}: MACRO {}", path, name);
+        }
+        assert_eq!(native, si2, "{}", path);
+    }
+}
@@ -50,4 +50,30 @@ EXTERN double lefiNoiseResistance_num (const lefiNoiseResistance* obj, int  inde
 EXTERN int lefiNoiseResistance_numVictims (const lefiNoiseResistance* obj);
 EXTERN const lefiNoiseVictim* lefiNoiseResistance_victim (const lefiNoiseResistance* obj, int  index);
 
+EXTERN double lefiNoiseEdge_edge (const lefiNoiseEdge* obj);
+EXTERN int lefiNoiseEdge_numResistances (const lefiNoiseEdge* obj);
+EXTERN const lefiNoiseResistance* lefiNoiseEdge_resistance (const lefiNoiseEdge* obj, int  index);
+
+EXTERN int lefiNoiseTable_num (const lefiNoiseTable* obj);
+EXTERN int lefiNoiseTable_numEdges (const lefiNoiseTable* obj);
+EXTERN const lefiNoiseEdge* lefiNoiseTable_edge (const lefiNoiseTable* obj, int  index);
+
+EXTERN double lefiCorrectionVictim_length (const lefiCorrectionVictim* obj);
+EXTERN int lefiCorrectionVictim_numCorrections (const lefiCorrectionVictim* obj);
+EXTERN double lefiCorrectionVictim_correction (const lefiCorrectionVictim* obj, int  index);
+
+EXTERN int lefiCorrectionResistance_numNums (const lefiCorrectionResistance* obj);
+EXTERN double lefiCorrectionResistance_num (const lefiCorrectionResistance* obj, int  index);
+
+EXTERN int lefiCorrectionResistance_numVictims (const lefiCorrectionResistance* obj);
+EXTERN const lefiCorrectionVictim* lefiCorrectionResistance_victim (const lefiCorrectionResistance* obj, int  index);
+
+EXTERN double lefiCorrectionEdge_edge (const lefiCorrectionEdge* obj);
+EXTERN int lefiCorrectionEdge_numResistances (const lefiCorrectionEdge* obj);
+EXTERN const lefiCorrectionResistance* lefiCorrectionEdge_resistance (const lefiCorrectionEdge* obj, int  index);
+
+EXTERN int lefiCorrectionTable_num (const lefiCorrectionTable* obj);
+EXTERN int lefiCorrectionTable_numEdges (const lefiCorrectionTable* obj);
+EXTERN const lefiCorrectionEdge* lefiCorrectionTable_edge (const lefiCorrectionTable* obj, int  index);
+
 #endif
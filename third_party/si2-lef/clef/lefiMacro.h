@@ -275,6 +275,101 @@ EXTERN const char* lefiMacro_foreignName (const lefiMacro* obj, int  index);
   /* Debug print                                                              */
 EXTERN void lefiMacro_print (const lefiMacro* obj, FILE*  f);
 
+  /* Obsolete 5.3 TIMING data                                                 */
+EXTERN int lefiTiming_numFromPins (const lefiTiming* obj);
+EXTERN const char* lefiTiming_fromPin (const lefiTiming* obj, int  index);
+EXTERN int lefiTiming_numToPins (const lefiTiming* obj);
+EXTERN const char* lefiTiming_toPin (const lefiTiming* obj, int  index);
+EXTERN int lefiTiming_hasTransition (const lefiTiming* obj);
+EXTERN int lefiTiming_hasDelay (const lefiTiming* obj);
+EXTERN int lefiTiming_hasRiseSlew (const lefiTiming* obj);
+EXTERN int lefiTiming_hasRiseSlew2 (const lefiTiming* obj);
+EXTERN int lefiTiming_hasFallSlew (const lefiTiming* obj);
+EXTERN int lefiTiming_hasFallSlew2 (const lefiTiming* obj);
+EXTERN int lefiTiming_hasRiseIntrinsic (const lefiTiming* obj);
+EXTERN int lefiTiming_hasFallIntrinsic (const lefiTiming* obj);
+EXTERN int lefiTiming_numOfAxisNumbers (const lefiTiming* obj);
+EXTERN int lefiTiming_numOfTableEntries (const lefiTiming* obj);
+EXTERN int lefiTiming_hasRiseRS (const lefiTiming* obj);
+EXTERN int lefiTiming_hasRiseCS (const lefiTiming* obj);
+EXTERN int lefiTiming_hasFallRS (const lefiTiming* obj);
+EXTERN int lefiTiming_hasFallCS (const lefiTiming* obj);
+EXTERN int lefiTiming_hasUnateness (const lefiTiming* obj);
+EXTERN int lefiTiming_hasRiseAtt1 (const lefiTiming* obj);
+EXTERN int lefiTiming_hasFallAtt1 (const lefiTiming* obj);
+EXTERN int lefiTiming_hasFallTo (const lefiTiming* obj);
+EXTERN int lefiTiming_hasRiseTo (const lefiTiming* obj);
+EXTERN int lefiTiming_hasStableTiming (const lefiTiming* obj);
+EXTERN int lefiTiming_hasSDFonePinTrigger (const lefiTiming* obj);
+EXTERN int lefiTiming_hasSDFtwoPinTrigger (const lefiTiming* obj);
+EXTERN int lefiTiming_hasSDFcondStart (const lefiTiming* obj);
+EXTERN int lefiTiming_hasSDFcondEnd (const lefiTiming* obj);
+EXTERN int lefiTiming_hasSDFcond (const lefiTiming* obj);
+EXTERN double* lefiTiming_axisNumbers (const lefiTiming* obj);
+EXTERN void lefiTiming_tableEntry (const lefiTiming* obj, int  num, double*  one, double*  two, double*  three);
+EXTERN const char* lefiTiming_delayRiseOrFall (const lefiTiming* obj);
+EXTERN const char* lefiTiming_delayUnateness (const lefiTiming* obj);
+EXTERN const char* lefiTiming_transitionRiseOrFall (const lefiTiming* obj);
+EXTERN const char* lefiTiming_transitionUnateness (const lefiTiming* obj);
+EXTERN const char* lefiTiming_unateness (const lefiTiming* obj);
+EXTERN const char* lefiTiming_stableRiseFall (const lefiTiming* obj);
+EXTERN const char* lefiTiming_SDFonePinTriggerType (const lefiTiming* obj);
+EXTERN const char* lefiTiming_SDFtwoPinTriggerType (const lefiTiming* obj);
+EXTERN const char* lefiTiming_SDFfromTrigger (const lefiTiming* obj);
+EXTERN const char* lefiTiming_SDFtoTrigger (const lefiTiming* obj);
+EXTERN const char* lefiTiming_SDFcondStart (const lefiTiming* obj);
+EXTERN const char* lefiTiming_SDFcondEnd (const lefiTiming* obj);
+EXTERN const char* lefiTiming_SDFcond (const lefiTiming* obj);
+EXTERN double lefiTiming_delayTableOne (const lefiTiming* obj);
+EXTERN double lefiTiming_delayTableTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_delayTableThree (const lefiTiming* obj);
+EXTERN double lefiTiming_transitionTableOne (const lefiTiming* obj);
+EXTERN double lefiTiming_transitionTableTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_transitionTableThree (const lefiTiming* obj);
+EXTERN double lefiTiming_SDFtriggerOne (const lefiTiming* obj);
+EXTERN double lefiTiming_SDFtriggerTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_SDFtriggerThree (const lefiTiming* obj);
+EXTERN double lefiTiming_fallIntrinsicOne (const lefiTiming* obj);
+EXTERN double lefiTiming_fallIntrinsicTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_fallIntrinsicThree (const lefiTiming* obj);
+EXTERN double lefiTiming_fallIntrinsicFour (const lefiTiming* obj);
+EXTERN double lefiTiming_riseIntrinsicOne (const lefiTiming* obj);
+EXTERN double lefiTiming_riseIntrinsicTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_riseIntrinsicThree (const lefiTiming* obj);
+EXTERN double lefiTiming_riseIntrinsicFour (const lefiTiming* obj);
+EXTERN double lefiTiming_fallSlewOne (const lefiTiming* obj);
+EXTERN double lefiTiming_fallSlewTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_fallSlewThree (const lefiTiming* obj);
+EXTERN double lefiTiming_fallSlewFour (const lefiTiming* obj);
+EXTERN double lefiTiming_fallSlewFive (const lefiTiming* obj);
+EXTERN double lefiTiming_fallSlewSix (const lefiTiming* obj);
+EXTERN double lefiTiming_fallSlewSeven (const lefiTiming* obj);
+EXTERN double lefiTiming_riseSlewOne (const lefiTiming* obj);
+EXTERN double lefiTiming_riseSlewTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_riseSlewThree (const lefiTiming* obj);
+EXTERN double lefiTiming_riseSlewFour (const lefiTiming* obj);
+EXTERN double lefiTiming_riseSlewFive (const lefiTiming* obj);
+EXTERN double lefiTiming_riseSlewSix (const lefiTiming* obj);
+EXTERN double lefiTiming_riseSlewSeven (const lefiTiming* obj);
+EXTERN double lefiTiming_riseRSOne (const lefiTiming* obj);
+EXTERN double lefiTiming_riseRSTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_riseCSOne (const lefiTiming* obj);
+EXTERN double lefiTiming_riseCSTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_fallRSOne (const lefiTiming* obj);
+EXTERN double lefiTiming_fallRSTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_fallCSOne (const lefiTiming* obj);
+EXTERN double lefiTiming_fallCSTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_riseAtt1One (const lefiTiming* obj);
+EXTERN double lefiTiming_riseAtt1Two (const lefiTiming* obj);
+EXTERN double lefiTiming_fallAtt1One (const lefiTiming* obj);
+EXTERN double lefiTiming_fallAtt1Two (const lefiTiming* obj);
+EXTERN double lefiTiming_fallToOne (const lefiTiming* obj);
+EXTERN double lefiTiming_fallToTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_riseToOne (const lefiTiming* obj);
+EXTERN double lefiTiming_riseToTwo (const lefiTiming* obj);
+EXTERN double lefiTiming_stableSetup (const lefiTiming* obj);
+EXTERN double lefiTiming_stableHold (const lefiTiming* obj);
+
   /* addSDF2Pins & addSDF1Pin are for 5.1                                     */
 
   /* The following are for 5.1                                                */
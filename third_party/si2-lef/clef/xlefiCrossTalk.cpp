@@ -65,3 +65,79 @@ const ::lefiNoiseVictim* lefiNoiseResistance_victim (const ::lefiNoiseResistance
     return (const ::lefiNoiseVictim*) ((LefDefParser::lefiNoiseResistance*)obj)->victim(index);
 }
 
+double lefiNoiseEdge_edge (const ::lefiNoiseEdge* obj) {
+    return ((LefDefParser::lefiNoiseEdge*)obj)->edge();
+}
+
+int lefiNoiseEdge_numResistances (const ::lefiNoiseEdge* obj) {
+    return ((LefDefParser::lefiNoiseEdge*)obj)->numResistances();
+}
+
+const ::lefiNoiseResistance* lefiNoiseEdge_resistance (const ::lefiNoiseEdge* obj, int  index) {
+    return (const ::lefiNoiseResistance*) ((LefDefParser::lefiNoiseEdge*)obj)->resistance(index);
+}
+
+int lefiNoiseTable_num (const ::lefiNoiseTable* obj) {
+    return ((LefDefParser::lefiNoiseTable*)obj)->num();
+}
+
+int lefiNoiseTable_numEdges (const ::lefiNoiseTable* obj) {
+    return ((LefDefParser::lefiNoiseTable*)obj)->numEdges();
+}
+
+const ::lefiNoiseEdge* lefiNoiseTable_edge (const ::lefiNoiseTable* obj, int  index) {
+    return (const ::lefiNoiseEdge*) ((LefDefParser::lefiNoiseTable*)obj)->edge(index);
+}
+
+double lefiCorrectionVictim_length (const ::lefiCorrectionVictim* obj) {
+    return ((LefDefParser::lefiCorrectionVictim*)obj)->length();
+}
+
+int lefiCorrectionVictim_numCorrections (const ::lefiCorrectionVictim* obj) {
+    return ((LefDefParser::lefiCorrectionVictim*)obj)->numCorrections();
+}
+
+double lefiCorrectionVictim_correction (const ::lefiCorrectionVictim* obj, int  index) {
+    return ((LefDefParser::lefiCorrectionVictim*)obj)->correction(index);
+}
+
+int lefiCorrectionResistance_numNums (const ::lefiCorrectionResistance* obj) {
+    return ((LefDefParser::lefiCorrectionResistance*)obj)->numNums();
+}
+
+double lefiCorrectionResistance_num (const ::lefiCorrectionResistance* obj, int  index) {
+    return ((LefDefParser::lefiCorrectionResistance*)obj)->num(index);
+}
+
+int lefiCorrectionResistance_numVictims (const ::lefiCorrectionResistance* obj) {
+    return ((LefDefParser::lefiCorrectionResistance*)obj)->numVictims();
+}
+
+const ::lefiCorrectionVictim* lefiCorrectionResistance_victim (const ::lefiCorrectionResistance* obj, int  index) {
+    return (const ::lefiCorrectionVictim*) ((LefDefParser::lefiCorrectionResistance*)obj)->victim(index);
+}
+
+double lefiCorrectionEdge_edge (const ::lefiCorrectionEdge* obj) {
+    return ((LefDefParser::lefiCorrectionEdge*)obj)->edge();
+}
+
+int lefiCorrectionEdge_numResistances (const ::lefiCorrectionEdge* obj) {
+    return ((LefDefParser::lefiCorrectionEdge*)obj)->numResistances();
+}
+
+const ::lefiCorrectionResistance* lefiCorrectionEdge_resistance (const ::lefiCorrectionEdge* obj, int  index) {
+    return (const ::lefiCorrectionResistance*) ((LefDefParser::lefiCorrectionEdge*)obj)->resistance(index);
+}
+
+int lefiCorrectionTable_num (const ::lefiCorrectionTable* obj) {
+    return ((LefDefParser::lefiCorrectionTable*)obj)->num();
+}
+
+int lefiCorrectionTable_numEdges (const ::lefiCorrectionTable* obj) {
+    return ((LefDefParser::lefiCorrectionTable*)obj)->numEdges();
+}
+
+const ::lefiCorrectionEdge* lefiCorrectionTable_edge (const ::lefiCorrectionTable* obj, int  index) {
+    return (const ::lefiCorrectionEdge*) ((LefDefParser::lefiCorrectionTable*)obj)->edge(index);
+}
+
@@ -827,3 +827,374 @@ void lefiMacro_print (const ::lefiMacro* obj, FILE*  f) {
     ((LefDefParser::lefiMacro*)obj)->print(f);
 }
 
+int lefiTiming_numFromPins (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->numFromPins();
+}
+
+const char* lefiTiming_fromPin (const ::lefiTiming* obj, int  index) {
+    return ((LefDefParser::lefiTiming*)obj)->fromPin(index);
+}
+
+int lefiTiming_numToPins (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->numToPins();
+}
+
+const char* lefiTiming_toPin (const ::lefiTiming* obj, int  index) {
+    return ((LefDefParser::lefiTiming*)obj)->toPin(index);
+}
+
+int lefiTiming_hasTransition (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasTransition();
+}
+
+int lefiTiming_hasDelay (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasDelay();
+}
+
+int lefiTiming_hasRiseSlew (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasRiseSlew();
+}
+
+int lefiTiming_hasRiseSlew2 (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasRiseSlew2();
+}
+
+int lefiTiming_hasFallSlew (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasFallSlew();
+}
+
+int lefiTiming_hasFallSlew2 (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasFallSlew2();
+}
+
+int lefiTiming_hasRiseIntrinsic (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasRiseIntrinsic();
+}
+
+int lefiTiming_hasFallIntrinsic (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasFallIntrinsic();
+}
+
+int lefiTiming_numOfAxisNumbers (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->numOfAxisNumbers();
+}
+
+int lefiTiming_numOfTableEntries (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->numOfTableEntries();
+}
+
+int lefiTiming_hasRiseRS (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasRiseRS();
+}
+
+int lefiTiming_hasRiseCS (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasRiseCS();
+}
+
+int lefiTiming_hasFallRS (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasFallRS();
+}
+
+int lefiTiming_hasFallCS (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasFallCS();
+}
+
+int lefiTiming_hasUnateness (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasUnateness();
+}
+
+int lefiTiming_hasRiseAtt1 (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasRiseAtt1();
+}
+
+int lefiTiming_hasFallAtt1 (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasFallAtt1();
+}
+
+int lefiTiming_hasFallTo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasFallTo();
+}
+
+int lefiTiming_hasRiseTo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasRiseTo();
+}
+
+int lefiTiming_hasStableTiming (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasStableTiming();
+}
+
+int lefiTiming_hasSDFonePinTrigger (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasSDFonePinTrigger();
+}
+
+int lefiTiming_hasSDFtwoPinTrigger (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasSDFtwoPinTrigger();
+}
+
+int lefiTiming_hasSDFcondStart (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasSDFcondStart();
+}
+
+int lefiTiming_hasSDFcondEnd (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasSDFcondEnd();
+}
+
+int lefiTiming_hasSDFcond (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->hasSDFcond();
+}
+
+double* lefiTiming_axisNumbers (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->axisNumbers();
+}
+
+void lefiTiming_tableEntry (const ::lefiTiming* obj, int  num, double*  one, double*  two, double*  three) {
+    ((LefDefParser::lefiTiming*)obj)->tableEntry(num, one, two, three);
+}
+
+const char* lefiTiming_delayRiseOrFall (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->delayRiseOrFall();
+}
+
+const char* lefiTiming_delayUnateness (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->delayUnateness();
+}
+
+const char* lefiTiming_transitionRiseOrFall (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->transitionRiseOrFall();
+}
+
+const char* lefiTiming_transitionUnateness (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->transitionUnateness();
+}
+
+const char* lefiTiming_unateness (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->unateness();
+}
+
+const char* lefiTiming_stableRiseFall (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->stableRiseFall();
+}
+
+const char* lefiTiming_SDFonePinTriggerType (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->SDFonePinTriggerType();
+}
+
+const char* lefiTiming_SDFtwoPinTriggerType (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->SDFtwoPinTriggerType();
+}
+
+const char* lefiTiming_SDFfromTrigger (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->SDFfromTrigger();
+}
+
+const char* lefiTiming_SDFtoTrigger (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->SDFtoTrigger();
+}
+
+const char* lefiTiming_SDFcondStart (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->SDFcondStart();
+}
+
+const char* lefiTiming_SDFcondEnd (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->SDFcondEnd();
+}
+
+const char* lefiTiming_SDFcond (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->SDFcond();
+}
+
+double lefiTiming_delayTableOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->delayTableOne();
+}
+
+double lefiTiming_delayTableTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->delayTableTwo();
+}
+
+double lefiTiming_delayTableThree (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->delayTableThree();
+}
+
+double lefiTiming_transitionTableOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->transitionTableOne();
+}
+
+double lefiTiming_transitionTableTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->transitionTableTwo();
+}
+
+double lefiTiming_transitionTableThree (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->transitionTableThree();
+}
+
+double lefiTiming_SDFtriggerOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->SDFtriggerOne();
+}
+
+double lefiTiming_SDFtriggerTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->SDFtriggerTwo();
+}
+
+double lefiTiming_SDFtriggerThree (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->SDFtriggerThree();
+}
+
+double lefiTiming_fallIntrinsicOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallIntrinsicOne();
+}
+
+double lefiTiming_fallIntrinsicTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallIntrinsicTwo();
+}
+
+double lefiTiming_fallIntrinsicThree (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallIntrinsicThree();
+}
+
+double lefiTiming_fallIntrinsicFour (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallIntrinsicFour();
+}
+
+double lefiTiming_riseIntrinsicOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseIntrinsicOne();
+}
+
+double lefiTiming_riseIntrinsicTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseIntrinsicTwo();
+}
+
+double lefiTiming_riseIntrinsicThree (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseIntrinsicThree();
+}
+
+double lefiTiming_riseIntrinsicFour (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseIntrinsicFour();
+}
+
+double lefiTiming_fallSlewOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallSlewOne();
+}
+
+double lefiTiming_fallSlewTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallSlewTwo();
+}
+
+double lefiTiming_fallSlewThree (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallSlewThree();
+}
+
+double lefiTiming_fallSlewFour (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallSlewFour();
+}
+
+double lefiTiming_fallSlewFive (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallSlewFive();
+}
+
+double lefiTiming_fallSlewSix (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallSlewSix();
+}
+
+double lefiTiming_fallSlewSeven (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallSlewSeven();
+}
+
+double lefiTiming_riseSlewOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseSlewOne();
+}
+
+double lefiTiming_riseSlewTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseSlewTwo();
+}
+
+double lefiTiming_riseSlewThree (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseSlewThree();
+}
+
+double lefiTiming_riseSlewFour (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseSlewFour();
+}
+
+double lefiTiming_riseSlewFive (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseSlewFive();
+}
+
+double lefiTiming_riseSlewSix (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseSlewSix();
+}
+
+double lefiTiming_riseSlewSeven (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseSlewSeven();
+}
+
+double lefiTiming_riseRSOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseRSOne();
+}
+
+double lefiTiming_riseRSTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseRSTwo();
+}
+
+double lefiTiming_riseCSOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseCSOne();
+}
+
+double lefiTiming_riseCSTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseCSTwo();
+}
+
+double lefiTiming_fallRSOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallRSOne();
+}
+
+double lefiTiming_fallRSTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallRSTwo();
+}
+
+double lefiTiming_fallCSOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallCSOne();
+}
+
+double lefiTiming_fallCSTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallCSTwo();
+}
+
+double lefiTiming_riseAtt1One (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseAtt1One();
+}
+
+double lefiTiming_riseAtt1Two (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseAtt1Two();
+}
+
+double lefiTiming_fallAtt1One (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallAtt1One();
+}
+
+double lefiTiming_fallAtt1Two (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallAtt1Two();
+}
+
+double lefiTiming_fallToOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallToOne();
+}
+
+double lefiTiming_fallToTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->fallToTwo();
+}
+
+double lefiTiming_riseToOne (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseToOne();
+}
+
+double lefiTiming_riseToTwo (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->riseToTwo();
+}
+
+double lefiTiming_stableSetup (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->stableSetup();
+}
+
+double lefiTiming_stableHold (const ::lefiTiming* obj) {
+    return ((LefDefParser::lefiTiming*)obj)->stableHold();
+}
@@ -6285,7 +6285,7 @@ timing_option:
   | two_pin_trigger from_pin_trigger to_pin_trigger K_TABLEDIMENSION int_number int_number int_number ';'
     { if (lefCallbacks->TimingCbk) lefData->lefrTiming.addSDF2Pins($1,$2,$3,$5,$6,$7); }
   | one_pin_trigger K_TABLEDIMENSION int_number int_number int_number ';' 
-    { if (lefCallbacks->TimingCbk) lefData->lefrTiming.addSDF1Pin($1,$3,$4,$4); }
+    { if (lefCallbacks->TimingCbk) lefData->lefrTiming.addSDF1Pin($1,$3,$4,$5); }
   | K_SDFCONDSTART QSTRING ';'
     { if (lefCallbacks->TimingCbk) lefData->lefrTiming.setSDFcondStart($2); }
   | K_SDFCONDEND QSTRING ';'
@@ -4091,18 +4091,21 @@ lefiTiming::addSDF2Pins(const char  *trigType,
 void
 lefiTiming::setSDFcondStart(const char *condStart)
 {
+    hasSDFcondStart_ = 1;
     SDFcondStart_ = (char*) condStart;
 }
 
 void
 lefiTiming::setSDFcondEnd(const char *condEnd)
 {
+    hasSDFcondEnd_ = 1;
     SDFcondEnd_ = (char*) condEnd;
 }
 
 void
 lefiTiming::setSDFcond(const char *cond)
 {
+    hasSDFcond_ = 1;
     SDFcond_ = (char*) cond;
 }
 